@@ -0,0 +1,120 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::{Anchor, IntoNode, Node, PaneAreas, PanePath, PaneTree, Panes, RenderPane, RenderRef};
+
+/// Renders a widget at the given width and height, placed by an [`Anchor`].
+///
+/// Leaves the rest of the area untouched, without the [`EmptyWidget`](crate::EmptyWidget)s
+/// nesting [`Rows`](crate::Rows) and [`Columns`](crate::Columns) would need.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Block, Paragraph, Widget}};
+/// use ratcl::{Align, Anchor};
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         Align(
+///             Paragraph::new("Loading...").block(Block::bordered()),
+///             Constraint::Length(14),
+///             Constraint::Length(3),
+///             Anchor::Top,
+///         ).render(area, buffer);
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Align<Content>(pub Content, pub Constraint, pub Constraint, pub Anchor);
+
+impl <Content> Align<Content> {
+    /// Centers a widget at the given width and height.
+    pub fn center(content: Content, width: Constraint, height: Constraint) -> Self {
+        Self(content, width, height, Anchor::Center)
+    }
+
+    fn area(&self, area: Rect) -> Rect {
+        self.3.area(area, self.1, self.2)
+    }
+}
+
+impl <Content: Widget> Widget for Align<Content> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let area = self.area(area);
+
+        self.0.render(area, buffer);
+    }
+}
+
+impl <Content: RenderRef> Widget for &Align<Content> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        self.0.render_ref(self.area(area), buffer);
+    }
+}
+
+impl <Content: StatefulWidget> StatefulWidget for Align<Content> {
+    type State = Content::State;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        let area = self.area(area);
+
+        self.0.render(area, buffer, state);
+    }
+}
+
+impl <Id, Content: Panes<Id>> Panes<Id> for Align<Content> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        self.0.pane_areas(self.area(area), path, areas);
+    }
+}
+
+/// A zoomed pane inside fills the whole area, rather than the aligned one.
+impl <Content: RenderPane> RenderPane for Align<Content> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        match path.is_empty() {
+            true => self.0.render_pane(path, self.area(area), buffer),
+            false => self.0.render_pane(path, area, buffer),
+        }
+    }
+}
+
+impl <'a, Id, Content> IntoNode<'a, Id> for Align<Content>
+where
+    Self: PaneTree<Id> + 'a,
+{
+    fn into_node(self) -> Node<'a, Id> {
+        Node::tree(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::widgets::Paragraph;
+
+    use super::*;
+
+    #[test]
+    fn aligns_content() {
+        let render = |align: Align<Paragraph>| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+
+            align.render(buffer.area, &mut buffer);
+
+            buffer
+        };
+
+        assert_eq!(
+            render(Align::center(Paragraph::new("ab"), Constraint::Length(2), Constraint::Length(1))),
+            Buffer::with_lines(["     ", "  ab ", "     "]),
+        );
+        assert_eq!(
+            render(Align(Paragraph::new("ab"), Constraint::Length(2), Constraint::Length(1), Anchor::BottomRight)),
+            Buffer::with_lines(["     ", "     ", "   ab"]),
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Margin, Position, Rect},
+    symbols::{border, line},
+    widgets::{Block, StatefulWidget, Widget},
+};
+
+use crate::{PaneAreas, PanePath, Panes, RenderPane, RenderRef};
+
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+/// Draws a border around its content and joins it with the dividers of the splits inside, so
+/// neighbouring panes share a single border line.
+///
+/// Use [`Split::divider`](crate::Split::divider) with the same [`line::Set`] to separate the panes.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, symbols::line, widgets::{Paragraph, Widget}};
+/// use ratcl::{CollapsedBorders, Columns, Rows, Split};
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         CollapsedBorders(
+///             Split::from(Columns(
+///                 Paragraph::new("Sidebar"),
+///                 Split::from(Rows(
+///                     Paragraph::new("Body"),
+///                     Paragraph::new("Footer"),
+///                     Constraint::Fill(1),
+///                 )).divider(line::NORMAL),
+///                 Constraint::Length(20),
+///             )).divider(line::NORMAL),
+///             line::NORMAL,
+///         ).render(area, buffer);
+///     }
+/// }
+/// ```
+pub struct CollapsedBorders<Content>(
+    pub Content,
+    pub line::Set,
+);
+
+impl <Content> CollapsedBorders<Content> {
+    fn render_border(&self, area: Rect, buffer: &mut Buffer) -> Rect {
+        let set = self.1;
+
+        Block::bordered()
+            .border_set(border::Set {
+                top_left: set.top_left,
+                top_right: set.top_right,
+                bottom_left: set.bottom_left,
+                bottom_right: set.bottom_right,
+                vertical_left: set.vertical,
+                vertical_right: set.vertical,
+                horizontal_top: set.horizontal,
+                horizontal_bottom: set.horizontal,
+            })
+            .render(area, buffer);
+
+        area.inner(Margin::new(1, 1))
+    }
+}
+
+/// Replaces line symbols that touch each other with the junction joining them.
+fn join_lines(set: line::Set, area: Rect, buffer: &mut Buffer) {
+    let arms_at = |buffer: &Buffer, x: u16, y: u16| {
+        let position = Position::new(x, y);
+
+        if area.contains(position) {
+            arms(&set, buffer[position].symbol())
+        } else {
+            0
+        }
+    };
+
+    let mut joined = Vec::new();
+
+    for position in area.positions() {
+        let (x, y) = (position.x, position.y);
+        let own = arms_at(buffer, x, y);
+
+        if own == 0 {
+            continue;
+        }
+
+        let mut arms = own;
+
+        if y > area.top() && arms_at(buffer, x, y - 1) & DOWN != 0 {
+            arms |= UP;
+        }
+        if arms_at(buffer, x, y.saturating_add(1)) & UP != 0 {
+            arms |= DOWN;
+        }
+        if x > area.left() && arms_at(buffer, x - 1, y) & RIGHT != 0 {
+            arms |= LEFT;
+        }
+        if arms_at(buffer, x.saturating_add(1), y) & LEFT != 0 {
+            arms |= RIGHT;
+        }
+
+        if arms != own {
+            joined.push((position, symbol(&set, arms)));
+        }
+    }
+
+    for (position, symbol) in joined {
+        buffer[position].set_symbol(symbol);
+    }
+}
+
+/// Returns the directions a line symbol reaches out to.
+fn arms(set: &line::Set, symbol: &str) -> u8 {
+    [
+        (set.vertical, UP | DOWN),
+        (set.horizontal, LEFT | RIGHT),
+        (set.top_right, LEFT | DOWN),
+        (set.top_left, RIGHT | DOWN),
+        (set.bottom_right, LEFT | UP),
+        (set.bottom_left, RIGHT | UP),
+        (set.vertical_left, UP | DOWN | LEFT),
+        (set.vertical_right, UP | DOWN | RIGHT),
+        (set.horizontal_down, LEFT | RIGHT | DOWN),
+        (set.horizontal_up, LEFT | RIGHT | UP),
+        (set.cross, UP | DOWN | LEFT | RIGHT),
+    ].into_iter()
+        .find(|(line, _)| *line == symbol)
+        .map_or(0, |(_, arms)| arms)
+}
+
+/// Returns the line symbol reaching out to the given directions.
+fn symbol(set: &line::Set, arms: u8) -> &'static str {
+    match arms {
+        arms if arms == LEFT | DOWN => set.top_right,
+        arms if arms == RIGHT | DOWN => set.top_left,
+        arms if arms == LEFT | UP => set.bottom_right,
+        arms if arms == RIGHT | UP => set.bottom_left,
+        arms if arms == UP | DOWN | LEFT => set.vertical_left,
+        arms if arms == UP | DOWN | RIGHT => set.vertical_right,
+        arms if arms == LEFT | RIGHT | DOWN => set.horizontal_down,
+        arms if arms == LEFT | RIGHT | UP => set.horizontal_up,
+        arms if arms == UP | DOWN | LEFT | RIGHT => set.cross,
+        arms if arms & (LEFT | RIGHT) != 0 => set.horizontal,
+        _ => set.vertical,
+    }
+}
+
+impl <Content: Widget> Widget for CollapsedBorders<Content> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let inner = self.render_border(area, buffer);
+        let set = self.1;
+
+        self.0.render(inner, buffer);
+        join_lines(set, area, buffer);
+    }
+}
+
+impl <Content: RenderRef> Widget for &CollapsedBorders<Content> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let inner = self.render_border(area, buffer);
+
+        self.0.render_ref(inner, buffer);
+        join_lines(self.1, area, buffer);
+    }
+}
+
+impl <Content: StatefulWidget> StatefulWidget for CollapsedBorders<Content> {
+    type State = Content::State;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        let inner = self.render_border(area, buffer);
+
+        self.0.render(inner, buffer, state);
+        join_lines(self.1, area, buffer);
+    }
+}
+
+impl <Id, Content: Panes<Id>> Panes<Id> for CollapsedBorders<Content> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        self.0.pane_areas(area.inner(Margin::new(1, 1)), path, areas);
+    }
+}
+
+impl <Content: RenderPane> RenderPane for CollapsedBorders<Content> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        if !path.is_empty() {
+            return self.0.render_pane(path, area, buffer);
+        }
+
+        let inner = self.render_border(area, buffer);
+
+        self.0.render_pane(path, inner, buffer);
+        join_lines(self.1, area, buffer);
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{layout::Constraint, widgets::Paragraph};
+
+    use crate::{Columns, Rows, Split};
+
+    use super::*;
+
+    #[test]
+    fn joins_borders() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 6));
+
+        CollapsedBorders(
+            Split::from(Columns(
+                Paragraph::new("ab"),
+                Split::from(Rows(
+                    Paragraph::new("cd"),
+                    Paragraph::new("ef"),
+                    Constraint::Length(1),
+                )).divider(line::NORMAL),
+                Constraint::Length(3),
+            )).divider(line::NORMAL),
+            line::ROUNDED,
+        ).render(buffer.area, &mut buffer);
+
+        let expected_buffer = Buffer::with_lines(vec![
+            "╭───┬───╮",
+            "│ab │cd │",
+            "│   ├───┤",
+            "│   │ef │",
+            "│   │   │",
+            "╰───┴───╯",
+        ]);
+
+        assert_eq!(buffer, expected_buffer);
+    }
+}
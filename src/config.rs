@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::Widget,
+};
+
+use crate::{panes::child_pane_areas, PaneAreas, PanePath, Panes, RenderPane, RenderRef};
+
+/// Widgets to fill the slots of a [`LayoutSpec`], keyed by slot name.
+pub type Slots<'a> = HashMap<&'a str, Box<dyn RenderRef + 'a>>;
+
+/// A layout described at runtime, such as one loaded from a config file.
+///
+/// With the `serde` feature, it can be read from any format `serde` supports. Splits hold a list
+/// of children, each with a `constraint` and the layout inside it, and leaves are named slots:
+///
+/// ```toml
+/// rows = [
+///     { constraint = { Length = 3 }, slot = "header" },
+///     { constraint = { Fill = 1 }, columns = [
+///         { constraint = { Percentage = 25 }, slot = "sidebar" },
+///         { constraint = { Fill = 1 }, slot = "body" },
+///     ] },
+/// ]
+/// ```
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Paragraph, Widget}};
+/// use ratcl::{ChildSpec, LayoutSpec, Slots};
+///
+/// struct SomeStruct {
+///     layout: LayoutSpec,
+/// }
+///
+/// impl Widget for &SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         let mut slots = Slots::new();
+///
+///         slots.insert("header", Box::new(Paragraph::new("Header")));
+///         slots.insert("body", Box::new(Paragraph::new("Body")));
+///
+///         self.layout
+///             .with_slots(&slots)
+///             .render(area, buffer);
+///     }
+/// }
+///
+/// let some_struct = SomeStruct {
+///     layout: LayoutSpec::Rows(vec![
+///         ChildSpec::new(Constraint::Length(3), LayoutSpec::slot("header")),
+///         ChildSpec::new(Constraint::Fill(1), LayoutSpec::slot("body")),
+///     ]),
+/// };
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum LayoutSpec {
+    /// Children stacked from top to bottom.
+    Rows(Vec<ChildSpec>),
+    /// Children placed from left to right.
+    Columns(Vec<ChildSpec>),
+    /// A widget looked up by name in the [`Slots`].
+    Slot(String),
+}
+
+/// A child of a split in a [`LayoutSpec`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChildSpec {
+    /// The size of the child in its split.
+    #[cfg_attr(feature = "serde", serde(with = "ConstraintDef"))]
+    pub constraint: Constraint,
+    /// The layout of the child.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub layout: LayoutSpec,
+}
+
+/// Lets `serde` read and write [`Constraint`]s, which `ratatui` does not do itself.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Constraint")]
+enum ConstraintDef {
+    Min(u16),
+    Max(u16),
+    Length(u16),
+    Percentage(u16),
+    Ratio(u32, u32),
+    Fill(u16),
+}
+
+impl ChildSpec {
+    /// Creates a child with the given constraint and layout.
+    pub fn new(constraint: Constraint, layout: LayoutSpec) -> Self {
+        Self {
+            constraint,
+            layout,
+        }
+    }
+}
+
+impl LayoutSpec {
+    /// Creates a slot with the given name.
+    pub fn slot<Name: Into<String>>(name: Name) -> Self {
+        Self::Slot(name.into())
+    }
+
+    /// Returns the name of every slot, in the order they are rendered.
+    pub fn slot_names(&self) -> Vec<&str> {
+        match self {
+            Self::Rows(children) | Self::Columns(children) => children.iter()
+                .flat_map(|child| child.layout.slot_names())
+                .collect(),
+            Self::Slot(name) => vec![name],
+        }
+    }
+
+    /// Fills the slots with widgets, making the layout renderable.
+    ///
+    /// Slots without a widget are left empty.
+    pub fn with_slots<'a>(&'a self, slots: &'a Slots<'a>) -> SlotLayout<'a> {
+        SlotLayout {
+            spec: self,
+            slots,
+        }
+    }
+
+    fn split(&self, area: Rect) -> Vec<(&LayoutSpec, Rect)> {
+        let (direction, children) = match self {
+            Self::Rows(children) => (Direction::Vertical, children),
+            Self::Columns(children) => (Direction::Horizontal, children),
+            Self::Slot(_) => return Vec::new(),
+        };
+
+        let rects = Layout::new(direction, children.iter().map(|child| child.constraint))
+            .split(area);
+
+        children.iter()
+            .map(|child| &child.layout)
+            .zip(rects.iter().copied())
+            .collect()
+    }
+}
+
+/// A [`LayoutSpec`] with its slots filled, created by [`LayoutSpec::with_slots`].
+///
+/// Its panes are keyed by slot name.
+pub struct SlotLayout<'a> {
+    spec: &'a LayoutSpec,
+    slots: &'a Slots<'a>,
+}
+
+impl SlotLayout<'_> {
+    fn child<'b>(&'b self, spec: &'b LayoutSpec) -> SlotLayout<'b> {
+        SlotLayout {
+            spec,
+            slots: self.slots,
+        }
+    }
+}
+
+impl Widget for SlotLayout<'_> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        self.render_ref(area, buffer);
+    }
+}
+
+impl Widget for &SlotLayout<'_> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        if let LayoutSpec::Slot(name) = self.spec
+            && let Some(widget) = self.slots.get(name.as_str())
+        {
+            widget.render_ref(area, buffer);
+        }
+
+        for (child, area) in self.spec.split(area) {
+            self.child(child).render_ref(area, buffer);
+        }
+    }
+}
+
+impl Panes<String> for SlotLayout<'_> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<String>) {
+        if let LayoutSpec::Slot(name) = self.spec {
+            areas.push(path, Some(name.clone()), area);
+        }
+
+        for (index, (child, area)) in self.spec.split(area).into_iter().enumerate() {
+            child_pane_areas(&self.child(child), index, area, path, areas);
+        }
+    }
+}
+
+impl RenderPane for SlotLayout<'_> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        let Some((index, path)) = path.split_first() else {
+            self.render_ref(area, buffer);
+
+            return true;
+        };
+
+        match self.spec {
+            LayoutSpec::Rows(children) | LayoutSpec::Columns(children) => children.get(*index)
+                .is_some_and(|child| self.child(&child.layout).render_pane(path, area, buffer)),
+            LayoutSpec::Slot(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::widgets::Paragraph;
+
+    use super::*;
+
+    fn some_spec() -> LayoutSpec {
+        LayoutSpec::Rows(vec![
+            ChildSpec::new(Constraint::Length(1), LayoutSpec::slot("header")),
+            ChildSpec::new(Constraint::Fill(1), LayoutSpec::Columns(vec![
+                ChildSpec::new(Constraint::Length(4), LayoutSpec::slot("side")),
+                ChildSpec::new(Constraint::Fill(1), LayoutSpec::slot("body")),
+            ])),
+        ])
+    }
+
+    #[test]
+    fn fills_slots() {
+        let spec = some_spec();
+        let mut slots = Slots::new();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+
+        slots.insert("header", Box::new(Paragraph::new("Header")));
+        slots.insert("body", Box::new(Paragraph::new("Body")));
+
+        spec.with_slots(&slots)
+            .render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["Header    ", "    Body  "]));
+        assert_eq!(spec.slot_names(), vec!["header", "side", "body"]);
+        assert_eq!(PaneAreas::of(&spec.with_slots(&slots), buffer.area).get(&"side".to_string()), Some(Rect::new(0, 1, 4, 1)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn loads_from_toml() {
+        let spec: LayoutSpec = toml::from_str(r#"
+            rows = [
+                { constraint = { Length = 1 }, slot = "header" },
+                { constraint = { Fill = 1 }, columns = [
+                    { constraint = { Length = 4 }, slot = "side" },
+                    { constraint = { Fill = 1 }, slot = "body" },
+                ] },
+            ]
+        "#).unwrap();
+
+        assert_eq!(spec, some_spec());
+        assert_eq!(toml::from_str::<LayoutSpec>(&toml::to_string(&spec).unwrap()).unwrap(), spec);
+    }
+}
@@ -0,0 +1,265 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::Widget,
+};
+
+use crate::{panes::child_pane_areas, IntoNode, Node, PaneAreas, PanePath, PaneTree, Panes, RenderPane, RenderRef};
+
+/// Which edges of a [`Dock`] own its corners, and so span its whole width or height.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Corners {
+    /// The top and bottom edges span the whole width, like a menu bar and a status bar.
+    #[default]
+    TopBottom,
+    /// The left and right edges span the whole height.
+    LeftRight,
+}
+
+/// A widget docked to one edge of a [`Dock`].
+struct Edge<'a, Id> {
+    content: Box<dyn PaneTree<Id> + 'a>,
+    size: Constraint,
+}
+
+/// The areas of a [`Dock`], with `None` for the edges left empty.
+struct DockAreas {
+    center: Rect,
+    edges: [Option<Rect>; 4],
+}
+
+/// Places widgets along the top, bottom, left and right edges of an area, around a center widget.
+///
+/// Every edge is optional, and which edges own the corners is set with [`Dock::corners`] rather
+/// than by how splits are nested. The `Id` is that of the [`Pane`](crate::Pane)s in the center
+/// and the edges.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Block, Paragraph, Widget}};
+/// use ratcl::{Corners, Dock};
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         let dock: Dock<_> = Dock::new(Paragraph::new("Editor").block(Block::bordered()))
+///             .top(Paragraph::new("File Edit View"), Constraint::Length(1))
+///             .bottom(Paragraph::new("Ready"), Constraint::Length(1))
+///             .left(Paragraph::new("Tree"), Constraint::Length(20))
+///             .right(Paragraph::new("Inspector"), Constraint::Percentage(25))
+///             .corners(Corners::TopBottom);
+///
+///         dock.render(area, buffer);
+///     }
+/// }
+/// ```
+pub struct Dock<'a, Center, Id = ()> {
+    center: Center,
+    edges: [Option<Edge<'a, Id>>; 4],
+    corners: Corners,
+}
+
+impl <'a, Center, Id> Dock<'a, Center, Id> {
+    const TOP: usize = 0;
+    const BOTTOM: usize = 1;
+    const LEFT: usize = 2;
+    const RIGHT: usize = 3;
+
+    /// Creates a dock with no edges around the given center.
+    pub fn new(center: Center) -> Self {
+        Self {
+            center,
+            edges: [None, None, None, None],
+            corners: Corners::TopBottom,
+        }
+    }
+
+    /// Docks a widget to the top edge with the given size.
+    pub fn top<Content: PaneTree<Id> + 'a, Size: Into<Constraint>>(self, content: Content, size: Size) -> Self {
+        self.edge(Self::TOP, content, size.into())
+    }
+
+    /// Docks a widget to the bottom edge with the given size.
+    pub fn bottom<Content: PaneTree<Id> + 'a, Size: Into<Constraint>>(self, content: Content, size: Size) -> Self {
+        self.edge(Self::BOTTOM, content, size.into())
+    }
+
+    /// Docks a widget to the left edge with the given size.
+    pub fn left<Content: PaneTree<Id> + 'a, Size: Into<Constraint>>(self, content: Content, size: Size) -> Self {
+        self.edge(Self::LEFT, content, size.into())
+    }
+
+    /// Docks a widget to the right edge with the given size.
+    pub fn right<Content: PaneTree<Id> + 'a, Size: Into<Constraint>>(self, content: Content, size: Size) -> Self {
+        self.edge(Self::RIGHT, content, size.into())
+    }
+
+    /// Sets which edges own the corners. Defaults to the top and bottom edges.
+    pub fn corners(mut self, corners: Corners) -> Self {
+        self.corners = corners;
+        self
+    }
+
+    fn edge<Content: PaneTree<Id> + 'a>(mut self, index: usize, content: Content, size: Constraint) -> Self {
+        self.edges[index] = Some(Edge {
+            content: Box::new(content),
+            size,
+        });
+        self
+    }
+
+    /// Splits an area between the edges at the given indices and whatever is left between them.
+    fn split(&self, area: Rect, direction: Direction, first: usize, second: usize) -> (Option<Rect>, Rect, Option<Rect>) {
+        let first = self.edges[first].as_ref().map(|edge| edge.size);
+        let second = self.edges[second].as_ref().map(|edge| edge.size);
+        let constraints = first.into_iter()
+            .chain([Constraint::Fill(1)])
+            .chain(second);
+        let areas = Layout::new(direction, constraints).split(area);
+        let middle = usize::from(first.is_some());
+
+        (first.map(|_| areas[0]), areas[middle], second.map(|_| areas[middle + 1]))
+    }
+
+    fn areas(&self, area: Rect) -> DockAreas {
+        let (top, bottom, left, right, center);
+
+        match self.corners {
+            Corners::TopBottom => {
+                let middle;
+                (top, middle, bottom) = self.split(area, Direction::Vertical, Self::TOP, Self::BOTTOM);
+                (left, center, right) = self.split(middle, Direction::Horizontal, Self::LEFT, Self::RIGHT);
+            },
+            Corners::LeftRight => {
+                let middle;
+                (left, middle, right) = self.split(area, Direction::Horizontal, Self::LEFT, Self::RIGHT);
+                (top, center, bottom) = self.split(middle, Direction::Vertical, Self::TOP, Self::BOTTOM);
+            },
+        }
+
+        DockAreas {
+            center,
+            edges: [top, bottom, left, right],
+        }
+    }
+
+    /// Renders every edge into its own area.
+    fn render_edges(&self, areas: &DockAreas, buffer: &mut Buffer) {
+        for (edge, area) in self.edges.iter().zip(areas.edges) {
+            if let (Some(edge), Some(area)) = (edge, area) {
+                edge.content.render_ref(area, buffer);
+            }
+        }
+    }
+}
+
+impl <Center: Widget, Id> Widget for Dock<'_, Center, Id> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let areas = self.areas(area);
+
+        self.render_edges(&areas, buffer);
+        self.center.render(areas.center, buffer);
+    }
+}
+
+impl <Center: RenderRef, Id> Widget for &Dock<'_, Center, Id> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let areas = self.areas(area);
+
+        self.render_edges(&areas, buffer);
+        self.center.render_ref(areas.center, buffer);
+    }
+}
+
+/// The center is the first child, followed by the top, bottom, left and right edges. An edge
+/// keeps its index when the others are left empty.
+impl <Id, Center: Panes<Id>> Panes<Id> for Dock<'_, Center, Id> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        let dock = self.areas(area);
+
+        child_pane_areas(&self.center, 0, dock.center, path, areas);
+
+        for (index, (edge, area)) in self.edges.iter().zip(dock.edges).enumerate() {
+            if let (Some(edge), Some(area)) = (edge, area) {
+                child_pane_areas(&*edge.content, index + 1, area, path, areas);
+            }
+        }
+    }
+}
+
+impl <Center: RenderPane, Id> RenderPane for Dock<'_, Center, Id> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        match path.split_first() {
+            None => {
+                let areas = self.areas(area);
+
+                self.render_edges(&areas, buffer);
+                self.center.render_pane(path, areas.center, buffer)
+            },
+            Some((0, path)) => self.center.render_pane(path, area, buffer),
+            Some((index, path)) => self.edges.get(index - 1)
+                .and_then(Option::as_ref)
+                .is_some_and(|edge| edge.content.render_pane(path, area, buffer)),
+        }
+    }
+}
+
+impl <'a, Id, Center> IntoNode<'a, Id> for Dock<'a, Center, Id>
+where
+    Self: PaneTree<Id> + 'a,
+{
+    fn into_node(self) -> Node<'a, Id> {
+        Node::tree(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Pane, Rows};
+
+    use super::*;
+
+    #[test]
+    fn docks_edges_around_center() {
+        let render = |corners: Corners| {
+            let dock: Dock<_> = Dock::new("c")
+                .top("ttttt", Constraint::Length(1))
+                .bottom("bbbbb", Constraint::Length(1))
+                .left("l", Constraint::Length(1))
+                .right("r", Constraint::Length(1))
+                .corners(corners);
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+
+            dock.render_ref(buffer.area, &mut buffer);
+
+            (buffer, PaneAreas::of(&dock, Rect::new(0, 0, 5, 3)).hit((0, 0)).map(|pane| pane.path.clone()))
+        };
+
+        assert_eq!(render(Corners::TopBottom), (Buffer::with_lines(["ttttt", "lc  r", "bbbbb"]), Some(vec![1])));
+        assert_eq!(render(Corners::LeftRight), (Buffer::with_lines(["ltttr", " c   ", " bbb "]), Some(vec![3])));
+    }
+
+    #[test]
+    fn skips_empty_edges() {
+        let dock: Dock<_> = Dock::new("center")
+            .right("r", Constraint::Length(1));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 1));
+
+        dock.render_ref(buffer.area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["centerr"]));
+        assert_eq!(PaneAreas::of(&dock, buffer.area).hit((6, 0)).map(|pane| pane.path.clone()), Some(vec![4]));
+    }
+
+    #[test]
+    fn keeps_edge_pane_ids() {
+        let dock = Dock::new(Pane("center", "c"))
+            .left(Rows(Pane("tree", "t"), Pane("outline", "o"), Constraint::Length(1)), Constraint::Length(1));
+        let areas = PaneAreas::of(&dock, Rect::new(0, 0, 3, 2));
+
+        assert_eq!(areas.get(&"center"), Some(Rect::new(1, 0, 2, 2)));
+        assert_eq!(areas.get(&"outline"), Some(Rect::new(0, 1, 1, 1)));
+        assert_eq!(areas.hit((0, 0)).map(|pane| pane.path.clone()), Some(vec![3, 0]));
+    }
+}
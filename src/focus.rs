@@ -0,0 +1,333 @@
+use ratatui::layout::{Direction, Rect};
+
+use crate::{PaneArea, PaneAreas, PanePath, Panes};
+
+/// Which leaf pane of a layout tree has focus, moved between panes by direction or in tab order.
+///
+/// Call [`FocusState::update`] with the layout before handling keys or rendering, so it knows
+/// where every pane is. The focus is kept by path, so it stays on the same pane as the terminal
+/// is resized. While that pane is hidden, such as by [`Split::hide_below`](crate::Split::hide_below),
+/// the first pane has focus instead, and the focus goes back once the pane is shown again.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, style::Stylize, widgets::{Block, Widget}};
+/// use ratcl::{Columns, FocusState, Pane, Rows};
+///
+/// struct App {
+///     focus: FocusState<&'static str>,
+/// }
+///
+/// impl App {
+///     fn render(&mut self, area: Rect, buffer: &mut Buffer) {
+///         let block = |id| {
+///             let block = Block::bordered().title(id);
+///
+///             match self.focus.is_focused(&id) {
+///                 true => block.yellow(),
+///                 false => block,
+///             }
+///         };
+///         let layout = Columns(
+///             Pane("sidebar", block("sidebar")),
+///             Rows(
+///                 Pane("body", block("body")),
+///                 Pane("footer", block("footer")),
+///                 Constraint::Fill(1),
+///             ),
+///             Constraint::Length(20),
+///         );
+///
+///         self.focus.update(&layout, area);
+///         layout.render(area, buffer);
+///     }
+///
+///     fn on_key(&mut self, key: char) {
+///         match key {
+///             'h' => self.focus.focus_left(),
+///             'l' => self.focus.focus_right(),
+///             'k' => self.focus.focus_up(),
+///             'j' => self.focus.focus_down(),
+///             '\t' => self.focus.focus_next(),
+///             _ => false,
+///         };
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FocusState<Id = ()> {
+    areas: PaneAreas<Id>,
+    focused: Option<PanePath>,
+    fallback: Option<PanePath>,
+}
+
+impl <Id> Default for FocusState<Id> {
+    fn default() -> Self {
+        Self {
+            areas: PaneAreas::default(),
+            focused: None,
+            fallback: None,
+        }
+    }
+}
+
+impl <Id> FocusState<Id> {
+    /// Creates a state with no pane focused, until the first update focuses the first pane.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Solves a layout tree for the given area, keeping the focus on the same pane.
+    ///
+    /// If that pane is gone or hidden, the first pane is focused until it is shown again.
+    pub fn update<Tree: Panes<Id> + ?Sized>(&mut self, tree: &Tree, area: Rect) {
+        self.areas = PaneAreas::of(tree, area);
+
+        let shown = self.focused.as_ref()
+            .is_some_and(|focused| self.panes().any(|pane| pane.path == *focused));
+
+        self.fallback = match shown {
+            true => None,
+            false => self.panes()
+                .next()
+                .map(|pane| pane.path.clone()),
+        };
+
+        if self.focused.is_none() {
+            self.focused = self.fallback.take();
+        }
+    }
+
+    /// Returns the focused pane, with its area as of the last update.
+    pub fn focused(&self) -> Option<&PaneArea<Id>> {
+        self.focused_pane()
+    }
+
+    /// Returns whether the pane with the given id has focus.
+    pub fn is_focused(&self, id: &Id) -> bool
+    where
+        Id: PartialEq,
+    {
+        self.focused_pane()
+            .is_some_and(|pane| pane.id.as_ref() == Some(id))
+    }
+
+    /// Returns whether the pane at the given path has focus.
+    pub fn is_focused_path(&self, path: &[usize]) -> bool {
+        self.focused_path().map(Vec::as_slice) == Some(path)
+    }
+
+    /// Focuses the pane with the given id, if there is one.
+    pub fn focus(&mut self, id: &Id) -> bool
+    where
+        Id: PartialEq,
+    {
+        let pane = self.panes()
+            .find(|pane| pane.id.as_ref() == Some(id));
+
+        self.set_focus(pane.map(|pane| pane.path.clone()))
+    }
+
+    /// Focuses the pane at the given path, even if it is not in the last update.
+    pub fn focus_path(&mut self, path: &[usize]) {
+        self.set_focus(Some(path.to_vec()));
+    }
+
+    /// Focuses the nearest pane to the left.
+    pub fn focus_left(&mut self) -> bool {
+        self.focus_towards(Direction::Horizontal, false)
+    }
+
+    /// Focuses the nearest pane to the right.
+    pub fn focus_right(&mut self) -> bool {
+        self.focus_towards(Direction::Horizontal, true)
+    }
+
+    /// Focuses the nearest pane above.
+    pub fn focus_up(&mut self) -> bool {
+        self.focus_towards(Direction::Vertical, false)
+    }
+
+    /// Focuses the nearest pane below.
+    pub fn focus_down(&mut self) -> bool {
+        self.focus_towards(Direction::Vertical, true)
+    }
+
+    /// Focuses the next pane in the order they are rendered, wrapping around.
+    pub fn focus_next(&mut self) -> bool {
+        self.focus_step(true)
+    }
+
+    /// Focuses the previous pane in the order they are rendered, wrapping around.
+    pub fn focus_prev(&mut self) -> bool {
+        self.focus_step(false)
+    }
+
+    /// Returns the panes that can be focused, skipping hidden ones.
+    fn panes(&self) -> impl Iterator<Item = &PaneArea<Id>> {
+        self.areas.iter()
+            .filter(|pane| !pane.area.is_empty())
+    }
+
+    /// Returns the path of the pane with focus, which is the first pane while the one asked for
+    /// is hidden.
+    fn focused_path(&self) -> Option<&PanePath> {
+        self.fallback.as_ref().or(self.focused.as_ref())
+    }
+
+    fn focused_pane(&self) -> Option<&PaneArea<Id>> {
+        let focused = self.focused_path()?;
+
+        self.panes()
+            .find(|pane| pane.path == *focused)
+    }
+
+    fn set_focus(&mut self, path: Option<PanePath>) -> bool {
+        match path {
+            Some(path) => {
+                self.focused = Some(path);
+                self.fallback = None;
+                true
+            },
+            None => false,
+        }
+    }
+
+    fn focus_step(&mut self, forward: bool) -> bool {
+        let panes = self.panes().collect::<Vec<_>>();
+        let Some(current) = self.focused_pane() else {
+            let first = if forward { panes.first() } else { panes.last() };
+
+            return self.set_focus(first.map(|pane| pane.path.clone()));
+        };
+
+        let index = panes.iter()
+            .position(|pane| pane.path == current.path)
+            .unwrap_or_default();
+        let next = match forward {
+            true => (index + 1) % panes.len(),
+            false => (index + panes.len() - 1) % panes.len(),
+        };
+
+        self.set_focus(Some(panes[next].path.clone()))
+    }
+
+    /// Focuses the closest pane past the focused one's edge, preferring panes beside it.
+    fn focus_towards(&mut self, direction: Direction, forward: bool) -> bool {
+        let Some(current) = self.focused_pane() else {
+            return self.focus_step(true);
+        };
+        let current = current.area;
+
+        // Works along the axis of movement and across it, with positions doubled so the centers
+        // stay whole.
+        let span = |area: Rect| match direction {
+            Direction::Horizontal => (area.left(), area.right(), area.top(), area.bottom()),
+            Direction::Vertical => (area.top(), area.bottom(), area.left(), area.right()),
+        };
+        let (start, end, across_start, across_end) = span(current);
+
+        let closest = self.panes()
+            .filter_map(|pane| {
+                let (pane_start, pane_end, pane_across_start, pane_across_end) = span(pane.area);
+                let distance = match forward {
+                    true => pane_start.checked_sub(end)?,
+                    false => start.checked_sub(pane_end)?,
+                };
+                let beside = pane_across_start < across_end && across_start < pane_across_end;
+                let offset = (u32::from(pane_across_start) + u32::from(pane_across_end))
+                    .abs_diff(u32::from(across_start) + u32::from(across_end));
+
+                Some(((!beside, distance, offset), pane.path.clone()))
+            })
+            .min_by_key(|(score, _)| *score)
+            .map(|(_, path)| path);
+
+        self.set_focus(closest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Constraint;
+
+    use crate::{Columns, EmptyWidget, Pane, Rows, Split};
+
+    use super::*;
+
+    #[test]
+    fn moves_focus_geometrically() {
+        let layout = Columns(
+            Pane("sidebar", EmptyWidget),
+            Rows(
+                Pane("body", EmptyWidget),
+                Columns(
+                    Pane("left", EmptyWidget),
+                    Pane("right", EmptyWidget),
+                    Constraint::Fill(1),
+                ),
+                Constraint::Fill(1),
+            ),
+            Constraint::Length(10),
+        );
+        let mut focus = FocusState::new();
+
+        focus.update(&layout, Rect::new(0, 0, 50, 20));
+
+        assert!(focus.is_focused(&"sidebar"));
+        assert!(!focus.focus_left());
+        assert!(focus.focus_right());
+        assert!(focus.is_focused(&"body"));
+        assert!(focus.focus_down());
+        assert!(focus.focus_right());
+        assert!(focus.is_focused(&"right"));
+        assert!(focus.focus_up());
+        assert!(focus.is_focused(&"body"));
+        assert!(focus.focus(&"left"));
+        assert!(focus.focus_left());
+        assert!(focus.is_focused(&"sidebar"));
+
+        focus.update(&layout, Rect::new(0, 0, 50, u16::MAX));
+        assert!(focus.focus_right());
+        assert!(focus.is_focused(&"body"));
+    }
+
+    #[test]
+    fn cycles_focus() {
+        let layout = Rows(
+            Pane(1, EmptyWidget),
+            Pane(2, EmptyWidget),
+            Constraint::Fill(1),
+        );
+        let mut focus = FocusState::new();
+
+        focus.update(&layout, Rect::new(0, 0, 10, 10));
+
+        assert!(focus.focus_next());
+        assert!(focus.is_focused(&2));
+        assert!(focus.focus_next());
+        assert!(focus.is_focused(&1));
+        assert!(focus.focus_prev());
+        assert_eq!(focus.focused().map(|pane| pane.area), Some(Rect::new(0, 5, 10, 5)));
+        assert!(focus.is_focused_path(&[1]));
+    }
+
+    #[test]
+    fn returns_focus_to_shown_pane() {
+        let layout = Split::columns([
+            (Pane(1, EmptyWidget), Constraint::Fill(1)),
+            (Pane(2, EmptyWidget), Constraint::Fill(1)),
+        ])
+            .hide_below(1, 5, 0);
+        let mut focus = FocusState::new();
+
+        focus.update(&layout, Rect::new(0, 0, 20, 1));
+        assert!(focus.focus(&2));
+
+        focus.update(&layout, Rect::new(0, 0, 8, 1));
+        assert!(focus.is_focused(&1));
+
+        focus.update(&layout, Rect::new(0, 0, 20, 1));
+        assert!(focus.is_focused(&2));
+    }
+}
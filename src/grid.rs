@@ -0,0 +1,226 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    widgets::Widget,
+};
+
+use crate::{panes::child_pane_areas, IntoNode, Node, PaneAreas, PanePath, PaneTree, Panes, RenderPane, RenderRef};
+
+/// A widget placed in a [`Grid`], covering one or more rows and columns.
+///
+/// The `Id` is that of the [`Pane`](crate::Pane)s inside it.
+pub struct GridCell<'a, Id = ()> {
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+    content: Box<dyn PaneTree<Id> + 'a>,
+}
+
+impl <'a, Id> GridCell<'a, Id> {
+    /// Creates a cell covering the given row and column.
+    pub fn new<Content: PaneTree<Id> + 'a>(row: usize, column: usize, content: Content) -> Self {
+        Self {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            content: Box::new(content),
+        }
+    }
+
+    /// Sets the number of rows the cell covers, starting from its row.
+    pub fn row_span(mut self, row_span: usize) -> Self {
+        self.row_span = row_span.max(1);
+        self
+    }
+
+    /// Sets the number of columns the cell covers, starting from its column.
+    pub fn column_span(mut self, column_span: usize) -> Self {
+        self.column_span = column_span.max(1);
+        self
+    }
+}
+
+/// Lays out widgets on rows and columns shared by the whole grid, so cells line up across rows.
+///
+/// The `Id` is that of the [`Pane`](crate::Pane)s in its cells, which keep their ids in
+/// [`PaneAreas`].
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Block, Widget}};
+/// use ratcl::{Grid, GridCell};
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         let grid: Grid = Grid::new(
+///             [Constraint::Length(3), Constraint::Fill(1), Constraint::Fill(1)],
+///             [Constraint::Length(20), Constraint::Fill(1), Constraint::Fill(1)],
+///         )
+///             .cell(GridCell::new(0, 0, Block::bordered().title("Header")).column_span(3))
+///             .cell(GridCell::new(1, 0, Block::bordered().title("Sidebar")).row_span(2))
+///             .cell(GridCell::new(1, 1, Block::bordered().title("One")))
+///             .cell(GridCell::new(1, 2, Block::bordered().title("Two")))
+///             .cell(GridCell::new(2, 1, Block::bordered().title("Three")).column_span(2));
+///
+///         grid.render(area, buffer);
+///     }
+/// }
+/// ```
+pub struct Grid<'a, Id = ()> {
+    rows: Vec<Constraint>,
+    columns: Vec<Constraint>,
+    spacing: u16,
+    cells: Vec<GridCell<'a, Id>>,
+}
+
+impl <'a, Id> Grid<'a, Id> {
+    /// Creates an empty grid with the given row and column constraints.
+    pub fn new<Rows, Columns>(rows: Rows, columns: Columns) -> Self
+    where
+        Rows: IntoIterator,
+        Rows::Item: Into<Constraint>,
+        Columns: IntoIterator,
+        Columns::Item: Into<Constraint>,
+    {
+        Self {
+            rows: rows.into_iter().map(Into::into).collect(),
+            columns: columns.into_iter().map(Into::into).collect(),
+            spacing: 0,
+            cells: Vec::new(),
+        }
+    }
+
+    /// Sets the number of empty cells between neighbouring rows and columns.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Adds a cell.
+    pub fn cell(mut self, cell: GridCell<'a, Id>) -> Self {
+        self.cells.push(cell);
+        self
+    }
+
+    /// Returns the area of every cell, or `None` for cells outside the grid.
+    fn cell_areas(&self, area: Rect) -> Vec<Option<Rect>> {
+        let rows = Layout::vertical(self.rows.iter().copied())
+            .spacing(self.spacing)
+            .split(area);
+        let columns = Layout::horizontal(self.columns.iter().copied())
+            .spacing(self.spacing)
+            .split(area);
+
+        let span = |tracks: &[Rect], start: usize, span: usize| {
+            let first = tracks.get(start)?;
+            let last = tracks[start.saturating_add(span).min(tracks.len()) - 1];
+
+            Some(first.union(last))
+        };
+
+        self.cells.iter()
+            .map(|cell| {
+                let rows = span(&rows, cell.row, cell.row_span)?;
+                let columns = span(&columns, cell.column, cell.column_span)?;
+
+                Some(Rect::new(columns.x, rows.y, columns.width, rows.height))
+            })
+            .collect()
+    }
+}
+
+impl <Id> Widget for Grid<'_, Id> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        self.render_ref(area, buffer);
+    }
+}
+
+impl <Id> Widget for &Grid<'_, Id> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        for (cell, area) in self.cells.iter().zip(self.cell_areas(area)) {
+            if let Some(area) = area {
+                cell.content.render_ref(area, buffer);
+            }
+        }
+    }
+}
+
+impl <Id> Panes<Id> for Grid<'_, Id> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        for (index, (cell, area)) in self.cells.iter().zip(self.cell_areas(area)).enumerate() {
+            if let Some(area) = area {
+                child_pane_areas(&*cell.content, index, area, path, areas);
+            }
+        }
+    }
+}
+
+impl <Id> RenderPane for Grid<'_, Id> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        let Some((index, path)) = path.split_first() else {
+            self.render_ref(area, buffer);
+
+            return true;
+        };
+
+        self.cells.get(*index)
+            .is_some_and(|cell| cell.content.render_pane(path, area, buffer))
+    }
+}
+
+impl <'a, Id: 'a> IntoNode<'a, Id> for Grid<'a, Id> {
+    fn into_node(self) -> Node<'a, Id> {
+        Node::tree(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::widgets::{Block, Paragraph};
+
+    use crate::{Columns, Pane};
+
+    use super::*;
+
+    #[test]
+    fn spans_cells() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 4));
+        let grid: Grid = Grid::new(
+            [Constraint::Length(1), Constraint::Length(1), Constraint::Fill(1)],
+            [Constraint::Length(3), Constraint::Fill(1), Constraint::Fill(1)],
+        )
+            .cell(GridCell::new(0, 0, Paragraph::new("head").centered()).column_span(3))
+            .cell(GridCell::new(1, 0, Block::bordered()).row_span(usize::MAX))
+            .cell(GridCell::new(1, 1, Paragraph::new("one")))
+            .cell(GridCell::new(1, 2, Paragraph::new("two")))
+            .cell(GridCell::new(2, 1, Paragraph::new("three")).column_span(2))
+            .cell(GridCell::new(3, 0, Paragraph::new("outside")));
+
+        grid.render(buffer.area, &mut buffer);
+
+        let expected_buffer = Buffer::with_lines(vec![
+            "  head   ",
+            "┌─┐onetwo",
+            "│ │three ",
+            "└─┘      ",
+        ]);
+
+        assert_eq!(buffer, expected_buffer);
+    }
+
+    #[test]
+    fn keeps_pane_ids() {
+        let grid = Grid::new([Constraint::Length(1), Constraint::Fill(1)], [Constraint::Fill(1)])
+            .cell(GridCell::new(0, 0, Pane("g", "a")))
+            .cell(GridCell::new(1, 0, Columns(Pane("left", "b"), Pane("right", "c"), Constraint::Length(1))));
+        let areas = PaneAreas::of(&grid, Rect::new(0, 0, 4, 3));
+
+        assert_eq!(areas.get(&"g"), Some(Rect::new(0, 0, 4, 1)));
+        assert_eq!(areas.get(&"right"), Some(Rect::new(1, 1, 3, 2)));
+        assert_eq!(areas.hit((0, 2)).map(|pane| pane.path.clone()), Some(vec![1, 0]));
+    }
+}
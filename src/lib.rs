@@ -2,7 +2,86 @@
 //!
 //! `ratcl` allows you to create complex `ratatui` layouts with a simple API.
 
-use ratatui::{buffer::Buffer, layout::{Constraint, Layout, Rect}, widgets::Widget};
+use std::rc::Rc;
+
+use ratatui::{buffer::Buffer, layout::{Constraint, Layout, Rect}, widgets::{StatefulWidget, Widget}};
+
+mod align;
+mod borders;
+mod config;
+mod dock;
+mod focus;
+mod grid;
+mod macros;
+mod node;
+mod padded;
+mod panes;
+mod resizable;
+mod responsive;
+mod scroll;
+mod split;
+mod split_n;
+mod stack;
+mod tabbed;
+mod template;
+mod tiling;
+mod zoom;
+
+pub use align::Align;
+pub use borders::CollapsedBorders;
+pub use config::{ChildSpec, LayoutSpec, SlotLayout, Slots};
+pub use dock::{Corners, Dock};
+pub use focus::FocusState;
+pub use grid::{Grid, GridCell};
+pub use node::{IntoNode, Node, PaneTree};
+pub use padded::{Inset, Insets, Padded};
+pub use panes::{hit_test, Pane, PaneArea, PaneAreas, PanePath, Panes};
+pub use resizable::{ResizableColumns, ResizableRows, ResizeState};
+pub use responsive::{Breakpoint, Responsive};
+pub use scroll::{Scroll, ScrollState};
+pub use split::Split;
+pub use split_n::{ColumnsN, NodeChildren, PaneChildren, RenderPaneChildren, RenderRefChildren, RowsN, SplitChildren, StatefulWidgetChildren, WidgetChildren};
+pub use stack::{Anchor, Layer, Stack};
+pub use tabbed::{TabBar, Tabbed, TabbedState};
+pub use template::{Template, TemplateError};
+pub use tiling::{PaneId, Tile, Tiling};
+pub use zoom::{RenderPane, Zoom, ZoomState};
+
+use panes::child_pane_areas;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use ratatui::layout::Constraint;
+}
+
+/// A widget that can be rendered by reference, without being consumed.
+///
+/// Implemented for every type whose reference is a [`Widget`], which includes the layouts of this
+/// crate, the widgets of ratatui and widgets implementing ratatui's unstable `WidgetRef`. To use
+/// your own widgets in layouts rendered by reference, implement [`Widget`] for a reference to them:
+///
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+///
+/// struct SomeStruct;
+///
+/// impl Widget for &SomeStruct {
+///     fn render(self, _: Rect, _: &mut Buffer) {}
+/// }
+/// ```
+pub trait RenderRef {
+    /// Renders the widget without consuming it.
+    fn render_ref(&self, area: Rect, buffer: &mut Buffer);
+}
+
+impl <Content> RenderRef for Content
+where
+    for<'a> &'a Content: Widget,
+{
+    fn render_ref(&self, area: Rect, buffer: &mut Buffer) {
+        self.render(area, buffer);
+    }
+}
 
 /// An empty widget.
 ///
@@ -40,8 +119,81 @@ impl Widget for EmptyWidget {
     fn render(self, _: Rect, _: &mut Buffer) {}
 }
 
+impl Widget for &EmptyWidget {
+    fn render(self, _: Rect, _: &mut Buffer) {}
+}
+
+/// Wraps a [`Widget`] so it can be laid out next to [`StatefulWidget`]s.
+///
+/// Its state is `()`.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{List, ListState, Paragraph, StatefulWidget}};
+/// use ratcl::{Rows, Stateless};
+///
+/// struct SomeStruct {
+///     states: ((), ListState),
+/// }
+///
+/// impl SomeStruct {
+///     fn render(&mut self, area: Rect, buffer: &mut Buffer) {
+///         let some_list = List::new(["One", "Two"]);
+///
+///         Rows(
+///             Stateless(Paragraph::new("Title")),
+///             some_list,
+///             Constraint::Length(1),
+///         ).render(area, buffer, &mut self.states);
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Stateless<Content: Widget>(pub Content);
+
+impl <Content: Widget> StatefulWidget for Stateless<Content> {
+    type State = ();
+
+    fn render(self, area: Rect, buffer: &mut Buffer, _: &mut ()) {
+        self.0.render(area, buffer);
+    }
+}
+
+/// The constraints of the two children of [`Rows`] or [`Columns`].
+///
+/// A [`Constraint`] is given to the first child and [`Second`] to the second, leaving the rest of
+/// the space to the other child. A pair of constraints gives one to each child.
+pub trait PairConstraints {
+    /// Returns the constraints of the first and second child.
+    fn constraints(&self) -> [Constraint; 2];
+}
+
+impl PairConstraints for Constraint {
+    fn constraints(&self) -> [Constraint; 2] {
+        [*self, Constraint::Fill(1)]
+    }
+}
+
+impl PairConstraints for (Constraint, Constraint) {
+    fn constraints(&self) -> [Constraint; 2] {
+        [self.0, self.1]
+    }
+}
+
+/// A constraint for the second child of [`Rows`] or [`Columns`], such as a fixed footer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Second(pub Constraint);
+
+impl PairConstraints for Second {
+    fn constraints(&self) -> [Constraint; 2] {
+        [Constraint::Fill(1), self.0]
+    }
+}
+
 /// Creates a pair of rows with a given constraint for the first row.
 ///
+/// Use [`Second`] to constrain the bottom row instead, or a pair of constraints for both.
+///
 /// # Example
 /// ```
 /// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Block, Paragraph, Widget}};
@@ -67,26 +219,96 @@ impl Widget for EmptyWidget {
 ///     }
 /// }
 /// ```
-pub struct Rows<TopContent: Widget, BottomContent: Widget>(
+pub struct Rows<TopContent, BottomContent, Sizes = Constraint>(
     pub TopContent,
     pub BottomContent,
-    pub Constraint,
+    pub Sizes,
 );
 
-impl <TopContent: Widget, BottomContent: Widget> Widget for Rows<TopContent, BottomContent> {
+impl <TopContent, BottomContent, Sizes: PairConstraints> Rows<TopContent, BottomContent, Sizes> {
+    fn split(&self, area: Rect) -> Rc<[Rect]> {
+        Layout::vertical(self.2.constraints())
+            .split(area)
+    }
+}
+
+impl <TopContent: Widget, BottomContent: Widget, Sizes: PairConstraints> Widget for Rows<TopContent, BottomContent, Sizes> {
     fn render(self, area: Rect, buffer: &mut Buffer) {
-        let rects = Layout::vertical([
-            self.2,
-            Constraint::Fill(1),
-        ]).split(area);
+        let rects = self.split(area);
 
         self.0.render(rects[0], buffer);
         self.1.render(rects[1], buffer);
     }
 }
 
+impl <TopContent: RenderRef, BottomContent: RenderRef, Sizes: PairConstraints> Widget for &Rows<TopContent, BottomContent, Sizes> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let rects = self.split(area);
+
+        self.0.render_ref(rects[0], buffer);
+        self.1.render_ref(rects[1], buffer);
+    }
+}
+
+impl <TopContent: StatefulWidget, BottomContent: StatefulWidget, Sizes: PairConstraints> StatefulWidget for Rows<TopContent, BottomContent, Sizes> {
+    type State = (TopContent::State, BottomContent::State);
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        let rects = self.split(area);
+
+        self.0.render(rects[0], buffer, &mut state.0);
+        self.1.render(rects[1], buffer, &mut state.1);
+    }
+}
+
+impl <Id, TopContent: Panes<Id>, BottomContent: Panes<Id>, Sizes: PairConstraints> Panes<Id> for Rows<TopContent, BottomContent, Sizes> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        let rects = self.split(area);
+
+        child_pane_areas(&self.0, 0, rects[0], path, areas);
+        child_pane_areas(&self.1, 1, rects[1], path, areas);
+    }
+}
+
+impl <TopContent: RenderPane, BottomContent: RenderPane, Sizes: PairConstraints> RenderPane for Rows<TopContent, BottomContent, Sizes> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        match path.split_first() {
+            None => {
+                let rects = self.split(area);
+
+                self.0.render_pane(&[], rects[0], buffer);
+                self.1.render_pane(&[], rects[1], buffer);
+
+                true
+            },
+            Some((0, path)) => self.0.render_pane(path, area, buffer),
+            Some((1, path)) => self.1.render_pane(path, area, buffer),
+            Some(_) => false,
+        }
+    }
+}
+
+impl <'a, Id, TopContent: IntoNode<'a, Id>, BottomContent: IntoNode<'a, Id>, Sizes: PairConstraints> IntoNode<'a, Id> for Rows<TopContent, BottomContent, Sizes> {
+    fn into_node(self) -> Node<'a, Id> {
+        let [first, second] = self.2.constraints();
+
+        Node::Rows(vec![
+            (first, self.0.into_node()),
+            (second, self.1.into_node()),
+        ])
+    }
+}
+
+impl <'a, Id, TopContent: IntoNode<'a, Id>, BottomContent: IntoNode<'a, Id>, Sizes: PairConstraints> From<Rows<TopContent, BottomContent, Sizes>> for Node<'a, Id> {
+    fn from(rows: Rows<TopContent, BottomContent, Sizes>) -> Self {
+        rows.into_node()
+    }
+}
+
 /// Creates a pair of columns with a given scale factor for the first column.
 ///
+/// Use [`Second`] to constrain the right column instead, or a pair of constraints for both.
+///
 /// # Example
 /// ```
 /// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Block, Paragraph, Widget}};
@@ -116,27 +338,95 @@ impl <TopContent: Widget, BottomContent: Widget> Widget for Rows<TopContent, Bot
 ///     }
 /// }
 /// ```
-pub struct Columns<LeftContent: Widget, RightContent: Widget>(
+pub struct Columns<LeftContent, RightContent, Sizes = Constraint>(
     pub LeftContent,
     pub RightContent,
-    pub Constraint,
+    pub Sizes,
 );
 
-impl <LeftContent: Widget, RightContent: Widget> Widget for Columns<LeftContent, RightContent> {
+impl <LeftContent, RightContent, Sizes: PairConstraints> Columns<LeftContent, RightContent, Sizes> {
+    fn split(&self, area: Rect) -> Rc<[Rect]> {
+        Layout::horizontal(self.2.constraints())
+            .split(area)
+    }
+}
+
+impl <LeftContent: Widget, RightContent: Widget, Sizes: PairConstraints> Widget for Columns<LeftContent, RightContent, Sizes> {
     fn render(self, area: Rect, buffer: &mut Buffer) {
-        let rects = Layout::horizontal([
-            self.2,
-            Constraint::Fill(1),
-        ]).split(area);
+        let rects = self.split(area);
 
         self.0.render(rects[0], buffer);
         self.1.render(rects[1], buffer);
     }
 }
 
+impl <LeftContent: RenderRef, RightContent: RenderRef, Sizes: PairConstraints> Widget for &Columns<LeftContent, RightContent, Sizes> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let rects = self.split(area);
+
+        self.0.render_ref(rects[0], buffer);
+        self.1.render_ref(rects[1], buffer);
+    }
+}
+
+impl <LeftContent: StatefulWidget, RightContent: StatefulWidget, Sizes: PairConstraints> StatefulWidget for Columns<LeftContent, RightContent, Sizes> {
+    type State = (LeftContent::State, RightContent::State);
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        let rects = self.split(area);
+
+        self.0.render(rects[0], buffer, &mut state.0);
+        self.1.render(rects[1], buffer, &mut state.1);
+    }
+}
+
+impl <Id, LeftContent: Panes<Id>, RightContent: Panes<Id>, Sizes: PairConstraints> Panes<Id> for Columns<LeftContent, RightContent, Sizes> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        let rects = self.split(area);
+
+        child_pane_areas(&self.0, 0, rects[0], path, areas);
+        child_pane_areas(&self.1, 1, rects[1], path, areas);
+    }
+}
+
+impl <LeftContent: RenderPane, RightContent: RenderPane, Sizes: PairConstraints> RenderPane for Columns<LeftContent, RightContent, Sizes> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        match path.split_first() {
+            None => {
+                let rects = self.split(area);
+
+                self.0.render_pane(&[], rects[0], buffer);
+                self.1.render_pane(&[], rects[1], buffer);
+
+                true
+            },
+            Some((0, path)) => self.0.render_pane(path, area, buffer),
+            Some((1, path)) => self.1.render_pane(path, area, buffer),
+            Some(_) => false,
+        }
+    }
+}
+
+impl <'a, Id, LeftContent: IntoNode<'a, Id>, RightContent: IntoNode<'a, Id>, Sizes: PairConstraints> IntoNode<'a, Id> for Columns<LeftContent, RightContent, Sizes> {
+    fn into_node(self) -> Node<'a, Id> {
+        let [first, second] = self.2.constraints();
+
+        Node::Columns(vec![
+            (first, self.0.into_node()),
+            (second, self.1.into_node()),
+        ])
+    }
+}
+
+impl <'a, Id, LeftContent: IntoNode<'a, Id>, RightContent: IntoNode<'a, Id>, Sizes: PairConstraints> From<Columns<LeftContent, RightContent, Sizes>> for Node<'a, Id> {
+    fn from(columns: Columns<LeftContent, RightContent, Sizes>) -> Self {
+        columns.into_node()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use ratatui::{symbols::border, widgets::{Block, Paragraph}};
+    use ratatui::{symbols::border, widgets::{Block, List, ListState, Paragraph}};
 
     use super::*;
 
@@ -215,4 +505,91 @@ mod tests {
         assert_eq!(buffer, expected_buffer);
         
     }
+
+    #[test]
+    fn renders_stateful_children() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 4));
+        let list = List::new(["One", "Two", "Three"])
+            .highlight_symbol(">");
+
+        let mut states = ((), (ListState::default().with_selected(Some(1)), ListState::default()));
+
+        StatefulWidget::render(
+            Rows(
+                Stateless(Paragraph::new("Lists")),
+                Columns(
+                    list.clone(),
+                    list,
+                    Constraint::Length(3),
+                ),
+                Constraint::Length(1),
+            ),
+            buffer.area,
+            &mut buffer,
+            &mut states,
+        );
+
+        let expected_buffer = Buffer::with_lines(vec![
+            "Lists ",
+            " OnOne",
+            ">TwTwo",
+            " ThThr",
+        ]);
+
+        assert_eq!(buffer, expected_buffer);
+        assert_eq!(states.1.0.selected(), Some(1));
+    }
+
+    #[test]
+    fn renders_by_reference() {
+        let layout = Rows(
+            Paragraph::new("Top"),
+            Columns(
+                Paragraph::new("Left"),
+                Paragraph::new("Right"),
+                Constraint::Length(5),
+            ),
+            Constraint::Length(1),
+        );
+
+        let expected_buffer = Buffer::with_lines(vec![
+            "Top       ",
+            "Left Right",
+        ]);
+
+        for _ in 0..2 {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+
+            (&layout).render(buffer.area, &mut buffer);
+
+            assert_eq!(buffer, expected_buffer);
+        }
+    }
+
+    #[test]
+    fn constrains_either_side() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+
+        Rows(
+            Columns(
+                Paragraph::new("Body"),
+                Paragraph::new("Side"),
+                Second(Constraint::Length(3)),
+            ),
+            Columns(
+                Paragraph::new("ab"),
+                Paragraph::new("cd"),
+                (Constraint::Length(3), Constraint::Length(2)),
+            ),
+            Second(Constraint::Length(1)),
+        ).render(buffer.area, &mut buffer);
+
+        let expected_buffer = Buffer::with_lines(vec![
+            "Body Sid",
+            "        ",
+            "ab cd   ",
+        ]);
+
+        assert_eq!(buffer, expected_buffer);
+    }
 }
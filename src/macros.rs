@@ -0,0 +1,229 @@
+/// Creates a [`Constraint`](ratatui::layout::Constraint) from a short form.
+///
+/// | Form             | Constraint                    |
+/// |------------------|-------------------------------|
+/// | `3`              | `Constraint::Length(3)`       |
+/// | `25%`            | `Constraint::Percentage(25)`  |
+/// | `1 / 3`          | `Constraint::Ratio(1, 3)`     |
+/// | `fill`           | `Constraint::Fill(1)`         |
+/// | `fill(2)`        | `Constraint::Fill(2)`         |
+/// | `min(5)`         | `Constraint::Min(5)`          |
+/// | `max(5)`         | `Constraint::Max(5)`          |
+/// | `(expression)`   | `expression`                  |
+///
+/// # Example
+/// ```
+/// use ratatui::layout::Constraint;
+/// use ratcl::constraint;
+///
+/// assert_eq!(constraint!(25%), Constraint::Percentage(25));
+/// assert_eq!(constraint!(fill(2)), Constraint::Fill(2));
+/// ```
+#[macro_export]
+macro_rules! constraint {
+    (fill) => {
+        $crate::__private::Constraint::Fill(1)
+    };
+    (fill($weight:expr)) => {
+        $crate::__private::Constraint::Fill($weight)
+    };
+    (min($size:expr)) => {
+        $crate::__private::Constraint::Min($size)
+    };
+    (max($size:expr)) => {
+        $crate::__private::Constraint::Max($size)
+    };
+    ($percentage:literal %) => {
+        $crate::__private::Constraint::Percentage($percentage)
+    };
+    ($numerator:literal / $denominator:literal) => {
+        $crate::__private::Constraint::Ratio($numerator, $denominator)
+    };
+    ($length:literal) => {
+        $crate::__private::Constraint::Length($length)
+    };
+    (($constraint:expr)) => {
+        $constraint
+    };
+}
+
+/// Creates [`RowsN`](crate::RowsN) from `constraint => child` pairs.
+///
+/// Constraints use the short forms of [`constraint!`](crate::constraint), and children may be
+/// nested `rows [...]` or `columns [...]` without the `!`.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::{Block, Paragraph, Widget}};
+/// use ratcl::{columns, rows};
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         let some_block = Block::bordered();
+///
+///         rows![
+///             3 => Paragraph::new("Header").block(some_block.clone()),
+///             fill => columns![
+///                 25% => Paragraph::new("Sidebar").block(some_block.clone()),
+///                 fill => Paragraph::new("Body").block(some_block),
+///             ],
+///         ].render(area, buffer);
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! rows {
+    ($($children:tt)*) => {
+        $crate::RowsN($crate::__split_children!([] [] $($children)*))
+    };
+}
+
+/// Creates [`ColumnsN`](crate::ColumnsN) from `constraint => child` pairs.
+///
+/// See [`rows!`](crate::rows) for the syntax.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::{Paragraph, Widget}};
+/// use ratcl::columns;
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         columns![
+///             20 => Paragraph::new("Files"),
+///             fill => rows [
+///                 fill => Paragraph::new("Editor"),
+///                 1 / 3 => Paragraph::new("Terminal"),
+///             ],
+///             (ratatui::layout::Constraint::Max(40)) => Paragraph::new("Outline"),
+///         ].render(area, buffer);
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! columns {
+    ($($children:tt)*) => {
+        $crate::ColumnsN($crate::__split_children!([] [] $($children)*))
+    };
+}
+
+/// Creates a layout from nested `rows [...]` and `columns [...]`.
+///
+/// See [`rows!`](crate::rows) for the syntax.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::{Paragraph, Widget}};
+/// use ratcl::layout;
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         layout!(rows [
+///             1 => Paragraph::new("Menu"),
+///             fill => columns [
+///                 30 => Paragraph::new("Tree"),
+///                 fill => Paragraph::new("Editor"),
+///             ],
+///             1 => Paragraph::new("Status"),
+///         ]).render(area, buffer);
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! layout {
+    (rows [$($children:tt)*]) => {
+        $crate::rows![$($children)*]
+    };
+    (columns [$($children:tt)*]) => {
+        $crate::columns![$($children)*]
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __split_children {
+    ([$($done:tt)*] [$($constraint:tt)+] => rows [$($inner:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::__split_children!(
+            [$($done)* ($crate::rows![$($inner)*], $crate::constraint!($($constraint)+)),]
+            []
+            $($($rest)*)?
+        )
+    };
+    ([$($done:tt)*] [$($constraint:tt)+] => columns [$($inner:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::__split_children!(
+            [$($done)* ($crate::columns![$($inner)*], $crate::constraint!($($constraint)+)),]
+            []
+            $($($rest)*)?
+        )
+    };
+    ([$($done:tt)*] [$($constraint:tt)+] => $child:expr $(, $($rest:tt)*)?) => {
+        $crate::__split_children!(
+            [$($done)* ($child, $crate::constraint!($($constraint)+)),]
+            []
+            $($($rest)*)?
+        )
+    };
+    ([$($done:tt)*] [$($constraint:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__split_children!([$($done)*] [$($constraint)* $next] $($rest)*)
+    };
+    ([$($done:tt)*] []) => {
+        ($($done)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Paragraph, Widget}};
+
+    use crate::{Columns, EmptyWidget, Rows};
+
+    #[test]
+    fn expands_constraints() {
+        assert_eq!(constraint!(3), Constraint::Length(3));
+        assert_eq!(constraint!(40%), Constraint::Percentage(40));
+        assert_eq!(constraint!(2 / 7), Constraint::Ratio(2, 7));
+        assert_eq!(constraint!(fill), Constraint::Fill(1));
+        assert_eq!(constraint!(fill(3)), Constraint::Fill(3));
+        assert_eq!(constraint!(min(4)), Constraint::Min(4));
+        assert_eq!(constraint!(max(5)), Constraint::Max(5));
+        assert_eq!(constraint!((Constraint::Length(6))), Constraint::Length(6));
+    }
+
+    #[test]
+    fn matches_nested_splits() {
+        let render = |widget: &dyn Fn(Rect, &mut Buffer)| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 4));
+
+            widget(buffer.area, &mut buffer);
+
+            buffer
+        };
+
+        let from_macro = render(&|area, buffer| layout!(rows [
+            1 => Paragraph::new("Header"),
+            fill => columns [
+                4 => Paragraph::new("Side"),
+                fill => Paragraph::new("Body"),
+            ],
+        ]).render(area, buffer));
+
+        let from_types = render(&|area, buffer| Rows(
+            Paragraph::new("Header"),
+            Columns(
+                Paragraph::new("Side"),
+                Paragraph::new("Body"),
+                Constraint::Length(4),
+            ),
+            Constraint::Length(1),
+        ).render(area, buffer));
+
+        assert_eq!(from_macro, from_types);
+        assert_eq!(render(&|area, buffer| rows![fill => EmptyWidget].render(area, buffer)), Buffer::empty(Rect::new(0, 0, 10, 4)));
+    }
+}
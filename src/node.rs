@@ -0,0 +1,276 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span, Text},
+    widgets::{
+        canvas::{Canvas, Context},
+        BarChart, Block, Chart, Clear, Gauge, LineGauge, List, Paragraph, Sparkline, Table, Tabs,
+        Widget,
+    },
+};
+
+use crate::{panes::child_pane_areas, EmptyWidget, Pane, PaneAreas, PanePath, Panes, RenderPane, RenderRef};
+
+/// A layout tree whose shape is decided at runtime.
+///
+/// Renders like [`RowsN`](crate::RowsN) and [`ColumnsN`](crate::ColumnsN), but its children may
+/// be of any widget type and any number. The `Id` is that of the [`Pane`]s it holds.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Block, Paragraph, Widget}};
+/// use ratcl::Node;
+///
+/// struct SomeStruct {
+///     pane_count: usize,
+/// }
+///
+/// impl Widget for &SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         let panes = (0..self.pane_count)
+///             .map(|index| (
+///                 Constraint::Fill(1),
+///                 Node::leaf(Paragraph::new(format!("Pane {index}")).block(Block::bordered())),
+///             ))
+///             .collect();
+///
+///         let node: Node = Node::Rows(vec![
+///             (Constraint::Length(1), Node::leaf(Paragraph::new("Header"))),
+///             (Constraint::Fill(1), Node::Columns(panes)),
+///         ]);
+///
+///         node.render(area, buffer);
+///     }
+/// }
+/// ```
+pub enum Node<'a, Id = ()> {
+    /// Children stacked from top to bottom, each with its constraint.
+    Rows(Vec<(Constraint, Node<'a, Id>)>),
+    /// Children placed from left to right, each with its constraint.
+    Columns(Vec<(Constraint, Node<'a, Id>)>),
+    /// A widget, which is a single pane without an id.
+    Leaf(Box<dyn RenderRef + 'a>),
+    /// A layout that keeps its own panes and their ids.
+    Tree(Box<dyn PaneTree<Id> + 'a>),
+}
+
+impl <'a, Id> Node<'a, Id> {
+    /// Creates a leaf holding the given widget.
+    pub fn leaf<Content: RenderRef + 'a>(content: Content) -> Self {
+        Self::Leaf(Box::new(content))
+    }
+
+    /// Creates a node holding the given layout, keeping its panes.
+    pub fn tree<Tree: PaneTree<Id> + 'a>(tree: Tree) -> Self {
+        Self::Tree(Box::new(tree))
+    }
+
+    fn split(&self, area: Rect) -> Vec<(&Node<'a, Id>, Rect)> {
+        let (direction, children) = match self {
+            Self::Rows(children) => (Direction::Vertical, children),
+            Self::Columns(children) => (Direction::Horizontal, children),
+            Self::Leaf(_) | Self::Tree(_) => return Vec::new(),
+        };
+
+        let rects = Layout::new(direction, children.iter().map(|(constraint, _)| *constraint))
+            .split(area);
+
+        children.iter()
+            .map(|(_, child)| child)
+            .zip(rects.iter().copied())
+            .collect()
+    }
+}
+
+impl <Id> Widget for Node<'_, Id> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        self.render_ref(area, buffer);
+    }
+}
+
+impl <Id> Widget for &Node<'_, Id> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        match self {
+            Node::Leaf(content) => content.render_ref(area, buffer),
+            Node::Tree(tree) => tree.render_ref(area, buffer),
+            Node::Rows(_) | Node::Columns(_) => {},
+        }
+
+        for (child, area) in self.split(area) {
+            child.render_ref(area, buffer);
+        }
+    }
+}
+
+impl <Id> Panes<Id> for Node<'_, Id> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        match self {
+            Self::Leaf(_) => areas.push(path, None, area),
+            Self::Tree(tree) => tree.pane_areas(area, path, areas),
+            Self::Rows(_) | Self::Columns(_) => {},
+        }
+
+        for (index, (child, area)) in self.split(area).into_iter().enumerate() {
+            child_pane_areas(child, index, area, path, areas);
+        }
+    }
+}
+
+impl <Id> RenderPane for Node<'_, Id> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        if let Self::Tree(tree) = self {
+            return tree.render_pane(path, area, buffer);
+        }
+
+        let Some((index, path)) = path.split_first() else {
+            self.render_ref(area, buffer);
+
+            return true;
+        };
+
+        match self {
+            Self::Rows(children) | Self::Columns(children) => children.get(*index)
+                .is_some_and(|(_, child)| child.render_pane(path, area, buffer)),
+            Self::Leaf(_) | Self::Tree(_) => false,
+        }
+    }
+}
+
+/// A layout that renders by reference, renders its panes and reports their areas, so it can be
+/// kept in a [`Node`] or another layout without losing its panes.
+pub trait PaneTree<Id = ()>: RenderRef + RenderPane + Panes<Id> {}
+
+impl <Id, Tree: RenderRef + RenderPane + Panes<Id> + ?Sized> PaneTree<Id> for Tree {}
+
+/// Converts a layout into a [`Node`], keeping the splits and panes inside it.
+///
+/// The `Id` is that of the [`Pane`]s in the layout. Implement it for your own widgets by making
+/// them leaves:
+///
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+/// use ratcl::{IntoNode, Node};
+///
+/// struct SomeStruct;
+///
+/// impl Widget for &SomeStruct {
+///     fn render(self, _: Rect, _: &mut Buffer) {}
+/// }
+///
+/// impl <'a, Id> IntoNode<'a, Id> for SomeStruct {
+///     fn into_node(self) -> Node<'a, Id> {
+///         Node::leaf(self)
+///     }
+/// }
+/// ```
+pub trait IntoNode<'a, Id = ()> {
+    /// Converts the layout into a [`Node`].
+    fn into_node(self) -> Node<'a, Id>;
+}
+
+impl <'a, Id> IntoNode<'a, Id> for Node<'a, Id> {
+    fn into_node(self) -> Node<'a, Id> {
+        self
+    }
+}
+
+impl <'a, Id: Clone + 'a, Content: RenderRef + 'a> IntoNode<'a, Id> for Pane<Id, Content> {
+    fn into_node(self) -> Node<'a, Id> {
+        Node::tree(self)
+    }
+}
+
+macro_rules! impl_leaf_nodes {
+    ($($leaf:ty),+ $(,)?) => {
+        $(impl <'a, Id> IntoNode<'a, Id> for $leaf {
+            fn into_node(self) -> Node<'a, Id> {
+                Node::leaf(self)
+            }
+        })+
+    };
+}
+
+impl_leaf_nodes!(
+    EmptyWidget,
+    Clear,
+    &'a str,
+    String,
+    Span<'a>,
+    Line<'a>,
+    Text<'a>,
+    Block<'a>,
+    Paragraph<'a>,
+    List<'a>,
+    Table<'a>,
+    Tabs<'a>,
+    Gauge<'a>,
+    LineGauge<'a>,
+    Sparkline<'a>,
+    BarChart<'a>,
+    Chart<'a>,
+);
+
+impl <'a, Id, F: Fn(&mut Context) + 'a> IntoNode<'a, Id> for Canvas<'a, F> {
+    fn into_node(self) -> Node<'a, Id> {
+        Node::leaf(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Columns, ColumnsN, Rows, Split};
+
+    use super::*;
+
+    #[test]
+    fn converts_static_layouts() {
+        let render = |node: Node| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+
+            node.render(buffer.area, &mut buffer);
+
+            buffer
+        };
+
+        let node = Rows(
+            Paragraph::new("Top"),
+            Columns(
+                "ab",
+                ColumnsN([
+                    ("cd", Constraint::Length(3)),
+                    ("ef", Constraint::Fill(1)),
+                ]),
+                Constraint::Length(3),
+            ),
+            Constraint::Length(1),
+        ).into_node();
+
+        assert!(matches!(&node, Node::Rows(children) if matches!(&children[1].1, Node::Columns(_))));
+        assert_eq!(PaneAreas::<()>::of(&node, Rect::new(0, 0, 8, 3)).len(), 4);
+
+        let expected_buffer = Buffer::with_lines(vec![
+            "Top     ",
+            "ab cd ef",
+            "        ",
+        ]);
+
+        assert_eq!(render(node), expected_buffer);
+    }
+
+    #[test]
+    fn keeps_pane_ids() {
+        let node = Columns(
+            Pane("left", "a"),
+            Split::rows([
+                (Pane("top", "b"), Constraint::Length(1)),
+                (Pane("bottom", "c"), Constraint::Fill(1)),
+            ]),
+            Constraint::Length(2),
+        ).into_node();
+        let areas = PaneAreas::of(&node, Rect::new(0, 0, 6, 3));
+
+        assert_eq!(areas.get(&"left"), Some(Rect::new(0, 0, 2, 3)));
+        assert_eq!(areas.get(&"bottom"), Some(Rect::new(2, 1, 4, 2)));
+        assert_eq!(areas.hit((3, 0)).map(|pane| pane.path.clone()), Some(vec![1, 0]));
+    }
+}
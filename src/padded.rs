@@ -0,0 +1,176 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::{IntoNode, Node, PaneAreas, PanePath, PaneTree, Panes, RenderPane, RenderRef};
+
+/// The space left on one side of a [`Padded`] widget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Inset {
+    /// A fixed number of cells.
+    Cells(u16),
+    /// A percentage of the width, for the left and right sides, or of the height, for the top and
+    /// bottom sides.
+    Percent(u16),
+}
+
+impl Inset {
+    fn cells(self, length: u16) -> u16 {
+        match self {
+            Self::Cells(cells) => cells,
+            Self::Percent(percent) => (u32::from(length) * u32::from(percent.min(100)) / 100) as u16,
+        }
+    }
+}
+
+impl Default for Inset {
+    fn default() -> Self {
+        Self::Cells(0)
+    }
+}
+
+impl From<u16> for Inset {
+    fn from(cells: u16) -> Self {
+        Self::Cells(cells)
+    }
+}
+
+/// The space left on every side of a [`Padded`] widget.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Insets {
+    pub top: Inset,
+    pub right: Inset,
+    pub bottom: Inset,
+    pub left: Inset,
+}
+
+impl Insets {
+    pub fn new(top: Inset, right: Inset, bottom: Inset, left: Inset) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// Leaves the same space on every side.
+    pub fn all(inset: Inset) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+
+    /// Leaves one space on the left and right sides, and another on the top and bottom sides.
+    pub fn symmetric(horizontal: Inset, vertical: Inset) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    /// Shrinks an area by the insets.
+    ///
+    /// Insets larger than the area leave it empty.
+    fn inner(self, area: Rect) -> Rect {
+        let left = self.left.cells(area.width).min(area.width);
+        let right = self.right.cells(area.width).min(area.width - left);
+        let top = self.top.cells(area.height).min(area.height);
+        let bottom = self.bottom.cells(area.height).min(area.height - top);
+
+        Rect::new(area.x + left, area.y + top, area.width - left - right, area.height - top - bottom)
+    }
+}
+
+/// Renders a widget inside its area, leaving space on each side.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Block, Paragraph, Widget}};
+/// use ratcl::{Columns, Inset, Insets, Padded};
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         Columns(
+///             Padded(
+///                 Paragraph::new("Sidebar"),
+///                 Insets::symmetric(Inset::Cells(2), Inset::Cells(1)),
+///             ),
+///             Padded(
+///                 Paragraph::new("Body").block(Block::bordered()),
+///                 Insets::all(Inset::Percent(10)),
+///             ),
+///             Constraint::Length(20),
+///         ).render(area, buffer);
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Padded<Content>(pub Content, pub Insets);
+
+impl <Content: Widget> Widget for Padded<Content> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        self.0.render(self.1.inner(area), buffer);
+    }
+}
+
+impl <Content: RenderRef> Widget for &Padded<Content> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        self.0.render_ref(self.1.inner(area), buffer);
+    }
+}
+
+impl <Content: StatefulWidget> StatefulWidget for Padded<Content> {
+    type State = Content::State;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        self.0.render(self.1.inner(area), buffer, state);
+    }
+}
+
+impl <Id, Content: Panes<Id>> Panes<Id> for Padded<Content> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        self.0.pane_areas(self.1.inner(area), path, areas);
+    }
+}
+
+/// A zoomed pane inside fills the whole area, without the insets.
+impl <Content: RenderPane> RenderPane for Padded<Content> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        match path.is_empty() {
+            true => self.0.render_pane(path, self.1.inner(area), buffer),
+            false => self.0.render_pane(path, area, buffer),
+        }
+    }
+}
+
+impl <'a, Id, Content> IntoNode<'a, Id> for Padded<Content>
+where
+    Self: PaneTree<Id> + 'a,
+{
+    fn into_node(self) -> Node<'a, Id> {
+        Node::tree(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Constraint;
+
+    use crate::{Columns, EmptyWidget};
+
+    use super::*;
+
+    #[test]
+    fn pads_content() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 4));
+
+        Columns(
+            Padded("ab", Insets::new(Inset::Cells(1), Inset::Cells(0), Inset::Cells(0), Inset::Percent(50))),
+            Padded(EmptyWidget, Insets::all(Inset::Cells(9))),
+            Constraint::Length(4),
+        ).render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["          ", "  ab      ", "          ", "          "]));
+        assert_eq!(Insets::all(Inset::Cells(9)).inner(Rect::new(4, 0, 6, 4)), Rect::new(10, 4, 0, 0));
+    }
+}
@@ -0,0 +1,341 @@
+use std::slice;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    text::{Line, Span, Text},
+    widgets::{
+        canvas::{Canvas, Context},
+        BarChart, Block, Chart, Clear, Gauge, LineGauge, List, Paragraph, RatatuiLogo, Scrollbar,
+        Sparkline, StatefulWidget, Table, Tabs, Widget,
+    },
+};
+
+use crate::{EmptyWidget, RenderRef, Stateless};
+
+/// The position of a pane in a layout tree.
+///
+/// Holds the index of the child taken at every split, starting from the root.
+pub type PanePath = Vec<usize>;
+
+/// A leaf pane and the area it was given.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaneArea<Id> {
+    /// The path to the pane from the root.
+    pub path: PanePath,
+    /// The id of the pane, if it is wrapped in a [`Pane`](crate::Pane).
+    pub id: Option<Id>,
+    /// The area the pane was given.
+    pub area: Rect,
+}
+
+/// The areas of every leaf pane in a layout tree, in the order they are rendered.
+///
+/// # Example
+/// ```
+/// use ratatui::{layout::{Constraint, Rect}, widgets::Paragraph};
+/// use ratcl::{Columns, Pane, PaneAreas, Rows};
+///
+/// let layout = Rows(
+///     Pane("header", Paragraph::new("Header")),
+///     Columns(
+///         Pane("sidebar", Paragraph::new("Sidebar")),
+///         Pane("body", Paragraph::new("Body")),
+///         Constraint::Length(20),
+///     ),
+///     Constraint::Length(3),
+/// );
+///
+/// let areas = PaneAreas::of(&layout, Rect::new(0, 0, 80, 24));
+///
+/// assert_eq!(areas.get(&"sidebar"), Some(Rect::new(0, 3, 20, 21)));
+/// assert_eq!(areas.get_path(&[1, 1]), Some(Rect::new(20, 3, 60, 21)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaneAreas<Id = ()> {
+    panes: Vec<PaneArea<Id>>,
+}
+
+impl <Id> Default for PaneAreas<Id> {
+    fn default() -> Self {
+        Self {
+            panes: Vec::new(),
+        }
+    }
+}
+
+impl <Id> PaneAreas<Id> {
+    /// Solves a layout tree for the given area without rendering it.
+    pub fn of<Tree: Panes<Id> + ?Sized>(tree: &Tree, area: Rect) -> Self {
+        let mut areas = Self::default();
+
+        tree.pane_areas(area, &mut PanePath::new(), &mut areas);
+
+        areas
+    }
+
+    /// Adds a leaf pane.
+    pub fn push(&mut self, path: &[usize], id: Option<Id>, area: Rect) {
+        self.panes.push(PaneArea {
+            path: path.to_vec(),
+            id,
+            area,
+        });
+    }
+
+    /// Returns the area of the pane with the given id.
+    pub fn get(&self, id: &Id) -> Option<Rect>
+    where
+        Id: PartialEq,
+    {
+        self.panes.iter()
+            .find(|pane| pane.id.as_ref() == Some(id))
+            .map(|pane| pane.area)
+    }
+
+    /// Returns the area of the pane at the given path.
+    pub fn get_path(&self, path: &[usize]) -> Option<Rect> {
+        self.panes.iter()
+            .find(|pane| pane.path == path)
+            .map(|pane| pane.area)
+    }
+
+    /// Returns the pane containing a position, such as the `(column, row)` of a mouse event.
+    ///
+    /// Where panes overlap, the one rendered last is returned.
+    pub fn hit<P: Into<Position>>(&self, position: P) -> Option<&PaneArea<Id>> {
+        let position = position.into();
+
+        self.panes.iter()
+            .rfind(|pane| pane.area.contains(position))
+    }
+
+    /// Returns an iterator over the panes, in the order they are rendered.
+    pub fn iter(&self) -> slice::Iter<'_, PaneArea<Id>> {
+        self.panes.iter()
+    }
+
+    /// Returns the number of panes.
+    pub fn len(&self) -> usize {
+        self.panes.len()
+    }
+
+    /// Returns whether there are no panes.
+    pub fn is_empty(&self) -> bool {
+        self.panes.is_empty()
+    }
+}
+
+impl <'a, Id> IntoIterator for &'a PaneAreas<Id> {
+    type Item = &'a PaneArea<Id>;
+    type IntoIter = slice::Iter<'a, PaneArea<Id>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Returns the leaf pane of a layout tree containing a position, such as the `(column, row)` of a
+/// mouse event.
+///
+/// # Example
+/// ```
+/// use ratatui::{layout::{Constraint, Rect}, widgets::Paragraph};
+/// use ratcl::{hit_test, Columns, Pane};
+///
+/// let layout = Columns(
+///     Pane("sidebar", Paragraph::new("Sidebar")),
+///     Pane("body", Paragraph::new("Body")),
+///     Constraint::Length(20),
+/// );
+///
+/// let hit = hit_test(&layout, Rect::new(0, 0, 80, 24), (42, 7));
+///
+/// assert_eq!(hit.and_then(|pane| pane.id), Some("body"));
+/// ```
+pub fn hit_test<Id, Tree: Panes<Id> + ?Sized, P: Into<Position>>(tree: &Tree, area: Rect, position: P) -> Option<PaneArea<Id>> {
+    let position = position.into();
+
+    PaneAreas::of(tree, area)
+        .panes
+        .into_iter()
+        .rfind(|pane| pane.area.contains(position))
+}
+
+/// A layout tree whose leaf panes can be found without rendering it.
+///
+/// Every widget is a leaf by default, so implementing this for your own widgets takes one line:
+///
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+/// use ratcl::Panes;
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, _: Rect, _: &mut Buffer) {}
+/// }
+///
+/// impl <Id> Panes<Id> for SomeStruct {}
+/// ```
+pub trait Panes<Id = ()> {
+    /// Adds the area of every leaf pane inside `area` to `areas`.
+    ///
+    /// `path` is the path of `self` and must be left as it was found.
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        areas.push(path, None, area);
+    }
+}
+
+/// Adds the panes of the child at `index` of a split.
+pub(crate) fn child_pane_areas<Id, Child: Panes<Id> + ?Sized>(
+    child: &Child,
+    index: usize,
+    area: Rect,
+    path: &mut PanePath,
+    areas: &mut PaneAreas<Id>,
+) {
+    path.push(index);
+    child.pane_areas(area, path, areas);
+    path.pop();
+}
+
+/// Gives a leaf pane an id, so its area can be looked up in [`PaneAreas`].
+///
+/// Renders exactly like the widget it holds.
+#[derive(Clone)]
+pub struct Pane<Id, Content>(pub Id, pub Content);
+
+impl <Id, Content: Widget> Widget for Pane<Id, Content> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        self.1.render(area, buffer);
+    }
+}
+
+impl <Id, Content: RenderRef> Widget for &Pane<Id, Content> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        self.1.render_ref(area, buffer);
+    }
+}
+
+impl <Id, Content: StatefulWidget> StatefulWidget for Pane<Id, Content> {
+    type State = Content::State;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        self.1.render(area, buffer, state);
+    }
+}
+
+impl <Id: Clone, Content> Panes<Id> for Pane<Id, Content> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        areas.push(path, Some(self.0.clone()), area);
+    }
+}
+
+impl <Id, Tree: Panes<Id> + ?Sized> Panes<Id> for &Tree {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        (**self).pane_areas(area, path, areas);
+    }
+}
+
+impl <Id, Tree: Panes<Id> + ?Sized> Panes<Id> for Box<Tree> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        (**self).pane_areas(area, path, areas);
+    }
+}
+
+macro_rules! impl_leaf_panes {
+    ($($leaf:ty),+ $(,)?) => {
+        $(impl <Id> Panes<Id> for $leaf {})+
+    };
+}
+
+impl_leaf_panes!(
+    EmptyWidget,
+    Clear,
+    RatatuiLogo,
+    str,
+    String,
+    Span<'_>,
+    Line<'_>,
+    Text<'_>,
+    Block<'_>,
+    Paragraph<'_>,
+    List<'_>,
+    Table<'_>,
+    Tabs<'_>,
+    Gauge<'_>,
+    LineGauge<'_>,
+    Sparkline<'_>,
+    BarChart<'_>,
+    Chart<'_>,
+    Scrollbar<'_>,
+);
+
+impl <Id, Content: Widget> Panes<Id> for Stateless<Content> {}
+
+impl <Id, F: Fn(&mut Context)> Panes<Id> for Canvas<'_, F> {}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Constraint;
+
+    use crate::{Columns, RowsN};
+
+    use super::*;
+
+    #[test]
+    fn finds_pane_areas() {
+        let layout = Columns(
+            Pane(1, Paragraph::new("Side")),
+            RowsN((
+                (Pane(2, Paragraph::new("Top")), Constraint::Length(2)),
+                (EmptyWidget, Constraint::Fill(1)),
+                (Pane(3, Paragraph::new("Bottom")), Constraint::Length(2)),
+            )),
+            Constraint::Percentage(25),
+        );
+
+        let areas = PaneAreas::of(&layout, Rect::new(0, 0, 40, 10));
+
+        let found = areas.iter()
+            .map(|pane| (pane.path.clone(), pane.id, pane.area))
+            .collect::<Vec<_>>();
+
+        assert_eq!(found, vec![
+            (vec![0], Some(1), Rect::new(0, 0, 10, 10)),
+            (vec![1, 0], Some(2), Rect::new(10, 0, 30, 2)),
+            (vec![1, 1], None, Rect::new(10, 2, 30, 6)),
+            (vec![1, 2], Some(3), Rect::new(10, 8, 30, 2)),
+        ]);
+        assert_eq!(areas.get(&3), Some(Rect::new(10, 8, 30, 2)));
+        assert_eq!(areas.get(&4), None);
+    }
+
+    #[test]
+    fn hits_nested_panes() {
+        let layout = Columns(
+            Pane("left", EmptyWidget),
+            Columns(
+                Pane("middle", EmptyWidget),
+                RowsN([
+                    (Pane("top", EmptyWidget), Constraint::Length(3)),
+                    (Pane("bottom", EmptyWidget), Constraint::Fill(1)),
+                ]),
+                Constraint::Length(10),
+            ),
+            Constraint::Length(10),
+        );
+        let area = Rect::new(5, 5, 30, 10);
+
+        let hit = |position: (u16, u16)| hit_test(&layout, area, position)
+            .map(|pane| (pane.path, pane.id));
+
+        assert_eq!(hit((5, 5)), Some((vec![0], Some("left"))));
+        assert_eq!(hit((24, 14)), Some((vec![1, 0], Some("middle"))));
+        assert_eq!(hit((25, 7)), Some((vec![1, 1, 0], Some("top"))));
+        assert_eq!(hit((34, 8)), Some((vec![1, 1, 1], Some("bottom"))));
+        assert_eq!(hit((4, 5)), None);
+        assert_eq!(hit((35, 14)), None);
+    }
+}
@@ -0,0 +1,353 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Position, Rect},
+    symbols::line,
+    widgets::{StatefulWidget, Widget},
+};
+
+/// The state of a [`ResizableRows`] or [`ResizableColumns`].
+///
+/// Holds the size of the first child, which is kept between `min` and `max`, and whether the
+/// divider is being dragged.
+///
+/// # Example
+/// ```
+/// use ratcl::ResizeState;
+///
+/// let mut state = ResizeState::new(30)
+///     .min(10)
+///     .max(60);
+///
+/// state.grow(40);
+///
+/// assert_eq!(state.size(), 60);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ResizeState {
+    size: u16,
+    min: u16,
+    max: Option<u16>,
+    dragging: bool,
+    direction: Option<Direction>,
+    area: Rect,
+}
+
+impl ResizeState {
+    /// Creates a state with the given size for the first child.
+    pub fn new(size: u16) -> Self {
+        Self {
+            size,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the smallest size of the first child.
+    pub fn min(mut self, min: u16) -> Self {
+        self.min = min;
+        self.size = self.clamp(self.size);
+        self
+    }
+
+    /// Sets the largest size of the first child.
+    pub fn max(mut self, max: u16) -> Self {
+        self.max = Some(max);
+        self.size = self.clamp(self.size);
+        self
+    }
+
+    /// Returns the size of the first child.
+    ///
+    /// The first child is drawn smaller when the area is too small for it, but the size is kept,
+    /// so it comes back when the area grows again.
+    pub fn size(&self) -> u16 {
+        self.size
+    }
+
+    /// Sets the size of the first child, keeping it between the min and max and inside the area
+    /// from the last render.
+    pub fn set_size(&mut self, size: u16) {
+        self.size = self.clamp(size).min(self.available());
+    }
+
+    /// Moves the divider away from the first child, starting from where it was last drawn.
+    pub fn grow(&mut self, by: u16) {
+        self.set_size(self.shown_size().saturating_add(by));
+    }
+
+    /// Moves the divider towards the first child, starting from where it was last drawn.
+    pub fn shrink(&mut self, by: u16) {
+        self.set_size(self.shown_size().saturating_sub(by));
+    }
+
+    /// Starts dragging if the position, such as that of a mouse press, is on the divider.
+    ///
+    /// Returns whether dragging started.
+    pub fn start_drag<P: Into<Position>>(&mut self, position: P) -> bool {
+        self.dragging = self.divider()
+            .is_some_and(|divider| divider.contains(position.into()));
+        self.dragging
+    }
+
+    /// Moves the divider to the position, such as that of a mouse drag, if dragging.
+    ///
+    /// Returns whether the divider moved.
+    pub fn drag<P: Into<Position>>(&mut self, position: P) -> bool {
+        let Some(direction) = self.direction.filter(|_| self.dragging) else {
+            return false;
+        };
+
+        let position = position.into();
+        let size = match direction {
+            Direction::Horizontal => position.x.saturating_sub(self.area.x),
+            Direction::Vertical => position.y.saturating_sub(self.area.y),
+        };
+        let previous_size = self.size;
+
+        self.set_size(size);
+
+        self.size != previous_size
+    }
+
+    /// Stops dragging.
+    pub fn end_drag(&mut self) {
+        self.dragging = false;
+    }
+
+    /// Returns whether the divider is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Returns the area of the divider from the last render.
+    pub fn divider(&self) -> Option<Rect> {
+        let [_, divider, _] = self.areas(self.direction?, self.area);
+
+        Some(divider)
+    }
+
+    fn clamp(&self, size: u16) -> u16 {
+        size.max(self.min).min(self.max.unwrap_or(u16::MAX))
+    }
+
+    /// Returns the largest size the first child can be drawn with in the area from the last
+    /// render, leaving room for the divider.
+    fn available(&self) -> u16 {
+        match self.direction {
+            Some(Direction::Horizontal) => self.area.width.saturating_sub(1),
+            Some(Direction::Vertical) => self.area.height.saturating_sub(1),
+            None => u16::MAX,
+        }
+    }
+
+    /// Returns the size the first child was last drawn with.
+    fn shown_size(&self) -> u16 {
+        self.size.min(self.available())
+    }
+
+    /// Splits an area, shrinking the first child to leave room for the divider without changing
+    /// the size in the state.
+    fn areas(&self, direction: Direction, area: Rect) -> [Rect; 3] {
+        let available = match direction {
+            Direction::Horizontal => area.width.saturating_sub(1),
+            Direction::Vertical => area.height.saturating_sub(1),
+        };
+
+        Layout::new(direction, [
+            Constraint::Length(self.size.min(available)),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+        ]).areas(area)
+    }
+
+    fn update(&mut self, direction: Direction, area: Rect) -> [Rect; 3] {
+        self.direction = Some(direction);
+        self.area = area;
+
+        self.areas(direction, area)
+    }
+}
+
+/// Creates a pair of rows divided by a line that can be moved at runtime.
+///
+/// The height of the first row is kept in a [`ResizeState`].
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::{Paragraph, StatefulWidget}};
+/// use ratcl::{ResizableRows, ResizeState};
+///
+/// struct SomeStruct {
+///     split: ResizeState,
+/// }
+///
+/// impl SomeStruct {
+///     fn render(&mut self, area: Rect, buffer: &mut Buffer) {
+///         ResizableRows(
+///             Paragraph::new("Editor"),
+///             Paragraph::new("Terminal"),
+///         ).render(area, buffer, &mut self.split);
+///     }
+///
+///     fn on_mouse_down(&mut self, column: u16, row: u16) {
+///         self.split.start_drag((column, row));
+///     }
+///
+///     fn on_mouse_drag(&mut self, column: u16, row: u16) {
+///         self.split.drag((column, row));
+///     }
+/// }
+/// ```
+pub struct ResizableRows<TopContent, BottomContent>(
+    pub TopContent,
+    pub BottomContent,
+);
+
+impl <TopContent: Widget, BottomContent: Widget> StatefulWidget for ResizableRows<TopContent, BottomContent> {
+    type State = ResizeState;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut ResizeState) {
+        let [top, divider, bottom] = state.update(Direction::Vertical, area);
+
+        self.0.render(top, buffer);
+        render_divider(line::HORIZONTAL, divider, buffer);
+        self.1.render(bottom, buffer);
+    }
+}
+
+/// Creates a pair of columns divided by a line that can be moved at runtime.
+///
+/// The width of the first column is kept in a [`ResizeState`].
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::{Paragraph, StatefulWidget}};
+/// use ratcl::{ResizableColumns, ResizeState};
+///
+/// struct SomeStruct {
+///     split: ResizeState,
+/// }
+///
+/// impl SomeStruct {
+///     fn render(&mut self, area: Rect, buffer: &mut Buffer) {
+///         ResizableColumns(
+///             Paragraph::new("Sidebar"),
+///             Paragraph::new("Logs"),
+///         ).render(area, buffer, &mut self.split);
+///     }
+///
+///     fn on_key_left(&mut self) {
+///         self.split.shrink(1);
+///     }
+///
+///     fn on_key_right(&mut self) {
+///         self.split.grow(1);
+///     }
+/// }
+/// ```
+pub struct ResizableColumns<LeftContent, RightContent>(
+    pub LeftContent,
+    pub RightContent,
+);
+
+impl <LeftContent: Widget, RightContent: Widget> StatefulWidget for ResizableColumns<LeftContent, RightContent> {
+    type State = ResizeState;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut ResizeState) {
+        let [left, divider, right] = state.update(Direction::Horizontal, area);
+
+        self.0.render(left, buffer);
+        render_divider(line::VERTICAL, divider, buffer);
+        self.1.render(right, buffer);
+    }
+}
+
+fn render_divider(symbol: &str, area: Rect, buffer: &mut Buffer) {
+    for position in area.positions() {
+        buffer[position].set_symbol(symbol);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::widgets::Paragraph;
+
+    use super::*;
+
+    #[test]
+    fn drags_divider() {
+        let mut state = ResizeState::new(2)
+            .min(1)
+            .max(6);
+        let render = |state: &mut ResizeState| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+
+            ResizableColumns(
+                Paragraph::new("aaaaaaaaaa"),
+                Paragraph::new("bbbbbbbbbb"),
+            ).render(buffer.area, &mut buffer, state);
+
+            buffer
+        };
+
+        assert_eq!(render(&mut state), Buffer::with_lines(["aa│bbbbbbb"]));
+
+        assert!(!state.start_drag((3, 0)));
+        assert!(state.start_drag((2, 0)));
+        assert!(state.drag((5, 0)));
+        assert_eq!(render(&mut state), Buffer::with_lines(["aaaaa│bbbb"]));
+
+        state.drag((9, 0));
+        state.end_drag();
+        assert!(!state.drag((0, 0)));
+        assert_eq!(render(&mut state), Buffer::with_lines(["aaaaaa│bbb"]));
+    }
+
+    #[test]
+    fn clamps_changes_to_area() {
+        let mut state = ResizeState::new(1);
+        let render = |state: &mut ResizeState| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 4));
+
+            ResizableRows(
+                Paragraph::new("a"),
+                Paragraph::new("b"),
+            ).render(buffer.area, &mut buffer, state);
+
+            buffer
+        };
+
+        render(&mut state);
+        state.grow(10);
+
+        assert_eq!(state.size(), 3);
+        assert_eq!(render(&mut state), Buffer::with_lines(["a", " ", " ", "─"]));
+
+        state.shrink(1);
+
+        assert_eq!(state.size(), 2);
+    }
+
+    #[test]
+    fn keeps_size_when_area_shrinks() {
+        let mut state = ResizeState::new(6);
+        let render = |width: u16, state: &mut ResizeState| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, width, 1));
+
+            ResizableColumns(
+                Paragraph::new("aaaaaaaaaa"),
+                Paragraph::new("bbbbbbbbbb"),
+            ).render(buffer.area, &mut buffer, state);
+
+            buffer
+        };
+
+        assert_eq!(render(4, &mut state), Buffer::with_lines(["aaa│"]));
+        assert_eq!(render(10, &mut state), Buffer::with_lines(["aaaaaa│bbb"]));
+        assert_eq!(state.size(), 6);
+
+        render(4, &mut state);
+        state.shrink(1);
+
+        assert_eq!(render(10, &mut state), Buffer::with_lines(["aa│bbbbbbb"]));
+    }
+}
@@ -0,0 +1,153 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::{panes::child_pane_areas, IntoNode, Node, PaneAreas, PanePath, PaneTree, Panes, RenderPane, RenderRef};
+
+/// The smallest area a [`Responsive`] layout needs to use its wide content.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Breakpoint {
+    MinWidth(u16),
+    MinHeight(u16),
+    MinSize {
+        width: u16,
+        height: u16,
+    },
+}
+
+impl Breakpoint {
+    /// Returns whether an area reaches the breakpoint.
+    pub fn fits(self, area: Rect) -> bool {
+        match self {
+            Self::MinWidth(width) => area.width >= width,
+            Self::MinHeight(height) => area.height >= height,
+            Self::MinSize { width, height } => area.width >= width && area.height >= height,
+        }
+    }
+}
+
+/// Renders the wide content when its area reaches the [`Breakpoint`], and the narrow content
+/// otherwise.
+///
+/// Nest it in the narrow content to add more breakpoints.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Paragraph, Widget}};
+/// use ratcl::{Breakpoint, Columns, Responsive, Rows};
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         let sidebar = Paragraph::new("Sidebar");
+///         let body = Paragraph::new("Body");
+///
+///         Responsive(
+///             Columns(sidebar.clone(), body.clone(), Constraint::Length(30)),
+///             Rows(sidebar, body, Constraint::Length(5)),
+///             Breakpoint::MinWidth(100),
+///         ).render(area, buffer);
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Responsive<WideContent, NarrowContent>(pub WideContent, pub NarrowContent, pub Breakpoint);
+
+impl <WideContent: Widget, NarrowContent: Widget> Widget for Responsive<WideContent, NarrowContent> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        if self.2.fits(area) {
+            self.0.render(area, buffer);
+        } else {
+            self.1.render(area, buffer);
+        }
+    }
+}
+
+impl <WideContent: RenderRef, NarrowContent: RenderRef> Widget for &Responsive<WideContent, NarrowContent> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        if self.2.fits(area) {
+            self.0.render_ref(area, buffer);
+        } else {
+            self.1.render_ref(area, buffer);
+        }
+    }
+}
+
+impl <WideContent: StatefulWidget, NarrowContent: StatefulWidget> StatefulWidget for Responsive<WideContent, NarrowContent> {
+    type State = (WideContent::State, NarrowContent::State);
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        if self.2.fits(area) {
+            self.0.render(area, buffer, &mut state.0);
+        } else {
+            self.1.render(area, buffer, &mut state.1);
+        }
+    }
+}
+
+/// The wide content is the first child and the narrow content the second, and only the one in
+/// use has panes.
+impl <Id, WideContent: Panes<Id>, NarrowContent: Panes<Id>> Panes<Id> for Responsive<WideContent, NarrowContent> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        if self.2.fits(area) {
+            child_pane_areas(&self.0, 0, area, path, areas);
+        } else {
+            child_pane_areas(&self.1, 1, area, path, areas);
+        }
+    }
+}
+
+impl <WideContent: RenderPane, NarrowContent: RenderPane> RenderPane for Responsive<WideContent, NarrowContent> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        match path.split_first() {
+            None if self.2.fits(area) => self.0.render_pane(path, area, buffer),
+            None => self.1.render_pane(path, area, buffer),
+            Some((0, path)) => self.0.render_pane(path, area, buffer),
+            Some((1, path)) => self.1.render_pane(path, area, buffer),
+            Some(_) => false,
+        }
+    }
+}
+
+impl <'a, Id, WideContent, NarrowContent> IntoNode<'a, Id> for Responsive<WideContent, NarrowContent>
+where
+    Self: PaneTree<Id> + 'a,
+{
+    fn into_node(self) -> Node<'a, Id> {
+        Node::tree(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{layout::Constraint, widgets::Paragraph};
+
+    use crate::{Columns, Rows};
+
+    use super::*;
+
+    #[test]
+    fn switches_at_breakpoint() {
+        let render = |width: u16| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, width, 2));
+
+            Columns(
+                Paragraph::new("ab"),
+                Responsive(
+                    Columns("cd", "ef", Constraint::Length(2)),
+                    Rows("cd", "ef", Constraint::Length(1)),
+                    Breakpoint::MinWidth(4),
+                ),
+                Constraint::Length(2),
+            ).render(buffer.area, &mut buffer);
+
+            buffer
+        };
+
+        assert_eq!(render(6), Buffer::with_lines(["abcdef", "      "]));
+        assert_eq!(render(5), Buffer::with_lines(["abcd ", "  ef "]));
+    }
+}
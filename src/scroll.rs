@@ -0,0 +1,261 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect, Size},
+    widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget, Widget},
+};
+
+use crate::Panes;
+
+/// The scroll offset of a [`Scroll`], and the sizes it was last rendered with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ScrollState {
+    offset: Position,
+    content: Size,
+    viewport: Size,
+}
+
+impl ScrollState {
+    /// Creates a state scrolled to the top left corner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the position of the content shown at the top left corner.
+    pub fn offset(&self) -> Position {
+        self.offset
+    }
+
+    /// Scrolls to show the given position of the content at the top left corner.
+    ///
+    /// The offset is kept inside the content when rendering.
+    pub fn scroll_to<P: Into<Position>>(&mut self, offset: P) {
+        self.offset = offset.into();
+    }
+
+    /// Scrolls up by the given number of rows, stopping at the top.
+    pub fn scroll_up(&mut self, rows: u16) {
+        self.offset.y = self.offset.y.saturating_sub(rows);
+    }
+
+    /// Scrolls down by the given number of rows, stopping at the bottom as of the last render.
+    ///
+    /// Does nothing before the first render, as the size of the content isn't known yet.
+    pub fn scroll_down(&mut self, rows: u16) {
+        self.offset.y = self.offset.y.saturating_add(rows).min(self.max_offset().y);
+    }
+
+    /// Scrolls left by the given number of columns, stopping at the left edge.
+    pub fn scroll_left(&mut self, columns: u16) {
+        self.offset.x = self.offset.x.saturating_sub(columns);
+    }
+
+    /// Scrolls right by the given number of columns, stopping at the right edge as of the last
+    /// render.
+    ///
+    /// Does nothing before the first render, as the size of the content isn't known yet.
+    pub fn scroll_right(&mut self, columns: u16) {
+        self.offset.x = self.offset.x.saturating_add(columns).min(self.max_offset().x);
+    }
+
+    /// Returns the size of the part of the content shown, as of the last render.
+    pub fn viewport(&self) -> Size {
+        self.viewport
+    }
+
+    /// Returns the furthest offset that still fills the viewport, as of the last render.
+    fn max_offset(&self) -> Position {
+        Position::new(
+            self.content.width.saturating_sub(self.viewport.width),
+            self.content.height.saturating_sub(self.viewport.height),
+        )
+    }
+
+    fn update(&mut self, content: Size, viewport: Size) {
+        self.content = content;
+        self.viewport = viewport;
+        self.offset.x = self.offset.x.min(self.max_offset().x);
+        self.offset.y = self.offset.y.min(self.max_offset().y);
+    }
+}
+
+/// Renders a widget at a size larger than its area, showing the part chosen by a
+/// [`ScrollState`].
+///
+/// The widget is rendered into a buffer of its own, and the visible part is copied into the area.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Paragraph, StatefulWidget, Widget}};
+/// use ratcl::{Columns, Scroll, ScrollState, Stateless};
+///
+/// struct App {
+///     state: ((), ScrollState),
+/// }
+///
+/// impl App {
+///     fn render(&mut self, area: Rect, buffer: &mut Buffer) {
+///         let lines = (1..=100)
+///             .map(|number| format!("Line {number}").into())
+///             .collect::<Vec<_>>();
+///
+///         Columns(
+///             Stateless(Paragraph::new("Sidebar")),
+///             Scroll::vertical(Paragraph::new(lines), 100)
+///                 .scrollbars(),
+///             Constraint::Length(20),
+///         ).render(area, buffer, &mut self.state);
+///     }
+/// }
+/// ```
+pub struct Scroll<Content> {
+    content: Content,
+    width: Option<u16>,
+    height: u16,
+    scrollbars: bool,
+}
+
+impl <Content> Scroll<Content> {
+    /// Renders the widget at the given size.
+    pub fn new(content: Content, size: Size) -> Self {
+        Self {
+            content,
+            width: Some(size.width),
+            height: size.height,
+            scrollbars: false,
+        }
+    }
+
+    /// Renders the widget at the given height and the width of the area.
+    pub fn vertical(content: Content, height: u16) -> Self {
+        Self {
+            content,
+            width: None,
+            height,
+            scrollbars: false,
+        }
+    }
+
+    /// Draws a scrollbar along the right side or the bottom when the content does not fit.
+    ///
+    /// Each scrollbar takes a column or row from the area.
+    pub fn scrollbars(mut self) -> Self {
+        self.scrollbars = true;
+        self
+    }
+}
+
+impl <Content: Widget> StatefulWidget for Scroll<Content> {
+    type State = ScrollState;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        let mut width = self.width.unwrap_or(area.width);
+        let mut viewport = area.as_size();
+        let mut vertical = false;
+        let mut horizontal = false;
+
+        // A scrollbar in one direction can leave the content too big in the other.
+        for _ in 0..2 {
+            vertical = self.scrollbars && self.height > viewport.height;
+            viewport.width = area.width - u16::from(vertical && area.width > 0);
+            width = self.width.unwrap_or(viewport.width);
+            horizontal = self.scrollbars && width > viewport.width;
+            viewport.height = area.height - u16::from(horizontal && area.height > 0);
+        }
+
+        let content = Size::new(width, self.height);
+        let mut scratch = Buffer::empty(Rect::from((Position::ORIGIN, content)));
+
+        self.content.render(scratch.area, &mut scratch);
+        state.update(content, viewport);
+
+        for y in 0..viewport.height.min(content.height - state.offset.y) {
+            for x in 0..viewport.width.min(content.width - state.offset.x) {
+                buffer[(area.x + x, area.y + y)] = scratch[(state.offset.x + x, state.offset.y + y)].clone();
+            }
+        }
+
+        // The scrollbars count the offsets the content can be scrolled to, not its size.
+        if vertical {
+            let mut scrollbar = ScrollbarState::new(usize::from(state.max_offset().y) + 1)
+                .position(state.offset.y.into())
+                .viewport_content_length(viewport.height.into());
+
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .render(Rect { height: viewport.height, ..area }, buffer, &mut scrollbar);
+        }
+
+        if horizontal {
+            let mut scrollbar = ScrollbarState::new(usize::from(state.max_offset().x) + 1)
+                .position(state.offset.x.into())
+                .viewport_content_length(viewport.width.into());
+
+            Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+                .render(Rect { width: viewport.width, ..area }, buffer, &mut scrollbar);
+        }
+    }
+}
+
+impl <Id, Content> Panes<Id> for Scroll<Content> {}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::widgets::Paragraph;
+
+    use super::*;
+
+    #[test]
+    fn shows_scrolled_window() {
+        let render = |state: &mut ScrollState| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+
+            Scroll::new(Paragraph::new(vec!["abcd".into(), "efgh".into(), "ijkl".into()]), Size::new(4, 3))
+                .render(buffer.area, &mut buffer, state);
+
+            buffer
+        };
+        let mut state = ScrollState::new();
+
+        assert_eq!(render(&mut state), Buffer::with_lines(["abc", "efg"]));
+
+        state.scroll_down(5);
+        state.scroll_right(1);
+        assert_eq!(render(&mut state), Buffer::with_lines(["fgh", "jkl"]));
+        assert_eq!(state.offset(), Position::new(1, 1));
+
+        state.scroll_to((9, 0));
+        assert_eq!(render(&mut state), Buffer::with_lines(["bcd", "fgh"]));
+    }
+
+    #[test]
+    fn draws_scrollbars() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        let mut state = ScrollState::new();
+
+        Scroll::vertical(Paragraph::new(vec!["ab".into(), "cd".into(), "ef".into(), "gh".into()]), 4)
+            .scrollbars()
+            .render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(state.viewport(), Size::new(2, 3));
+        assert_eq!(buffer, Buffer::with_lines(["ab▲", "cd█", "ef▼"]));
+    }
+
+    #[test]
+    fn moves_thumb_to_bottom() {
+        let render = |state: &mut ScrollState| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 6));
+            let lines = ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"];
+
+            Scroll::vertical(Paragraph::new(lines.map(Into::into).to_vec()), 10)
+                .scrollbars()
+                .render(buffer.area, &mut buffer, state);
+
+            buffer
+        };
+        let mut state = ScrollState::new();
+
+        assert_eq!(render(&mut state), Buffer::with_lines(["a▲", "b█", "c█", "d║", "e║", "f▼"]));
+
+        state.scroll_down(10);
+        assert_eq!(render(&mut state), Buffer::with_lines(["e▲", "f║", "g║", "h█", "i█", "j▼"]));
+    }
+}
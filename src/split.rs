@@ -0,0 +1,432 @@
+use std::rc::Rc;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Flex, Layout, Rect},
+    symbols::line,
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::{
+    zoom::render_child_panes, Columns, ColumnsN, IntoNode, Node, PairConstraints, PaneAreas,
+    PaneChildren, PanePath, PaneTree, Panes, RenderPane, RenderPaneChildren, RenderRefChildren,
+    Rows, RowsN, SplitChildren, StatefulWidgetChildren, WidgetChildren,
+};
+
+/// A split with extra settings, such as the space between its children and how they are packed.
+///
+/// Any of [`Rows`], [`Columns`], [`RowsN`] and [`ColumnsN`] can be turned into one with
+/// [`Split::from`].
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, symbols::line, widgets::{Paragraph, Widget}};
+/// use ratcl::{Columns, Split};
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         Split::from(Columns(
+///             Paragraph::new("Sidebar"),
+///             Paragraph::new("Body"),
+///             Constraint::Length(20),
+///         ))
+///             .spacing(3)
+///             .divider(line::NORMAL)
+///             .render(area, buffer);
+///     }
+/// }
+/// ```
+pub struct Split<Children> {
+    children: Children,
+    direction: Direction,
+    spacing: u16,
+    flex: Flex,
+    divider: Option<line::Set>,
+    hiding: Vec<Hiding>,
+}
+
+/// When a child of a [`Split`] may be hidden, set by [`Split::hide_below`].
+struct Hiding {
+    index: usize,
+    min: u16,
+    priority: u16,
+}
+
+impl <Children> Split<Children> {
+    /// Creates rows from children paired with their constraints, like [`RowsN`].
+    pub fn rows(children: Children) -> Self {
+        Self::new(children, Direction::Vertical)
+    }
+
+    /// Creates columns from children paired with their constraints, like [`ColumnsN`].
+    pub fn columns(children: Children) -> Self {
+        Self::new(children, Direction::Horizontal)
+    }
+
+    fn new(children: Children, direction: Direction) -> Self {
+        Self {
+            children,
+            direction,
+            spacing: 0,
+            flex: Flex::default(),
+            divider: None,
+            hiding: Vec::new(),
+        }
+    }
+
+    /// Sets the number of empty cells between neighbouring children.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets how the children are packed when they don't fill the split, such as at its start or
+    /// with space around them.
+    ///
+    /// Children with a `Fill` constraint take all the space left, so this only changes splits
+    /// without one. [`Rows`] and [`Columns`] with a single constraint give the other child
+    /// `Fill(1)`, so they can only be packed when given a pair of constraints.
+    pub fn flex(mut self, flex: Flex) -> Self {
+        self.flex = flex;
+        self
+    }
+
+    /// Draws a line in the middle of the space between neighbouring children.
+    ///
+    /// The spacing is raised to one cell if it is zero.
+    pub fn divider(mut self, divider: line::Set) -> Self {
+        self.spacing = self.spacing.max(1);
+        self.divider = Some(divider);
+        self
+    }
+
+    /// Gives the child at `index` a minimum size and a priority.
+    ///
+    /// While any child is smaller than its minimum, the child with the lowest priority is hidden
+    /// and its space is shared by the others. Hidden children are given an empty area.
+    ///
+    /// An index past the last child is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Paragraph, Widget}};
+    /// use ratcl::{Columns, Split};
+    ///
+    /// struct SomeStruct;
+    ///
+    /// impl Widget for SomeStruct {
+    ///     fn render(self, area: Rect, buffer: &mut Buffer) {
+    ///         // Below 50 columns, the sidebar is hidden to keep the body usable.
+    ///         Split::from(Columns(
+    ///             Paragraph::new("Sidebar"),
+    ///             Paragraph::new("Body"),
+    ///             Constraint::Percentage(30),
+    ///         ))
+    ///             .hide_below(0, 15, 0)
+    ///             .hide_below(1, 35, 1)
+    ///             .render(area, buffer);
+    ///     }
+    /// }
+    /// ```
+    pub fn hide_below(mut self, index: usize, min: u16, priority: u16) -> Self {
+        self.hiding.retain(|hiding| hiding.index != index);
+        self.hiding.push(Hiding {
+            index,
+            min,
+            priority,
+        });
+        self
+    }
+}
+
+impl <Children: SplitChildren> Split<Children> {
+    fn layout(&self, shown: &[bool]) -> Layout {
+        let constraints = self.children.constraints()
+            .into_iter()
+            .zip(shown)
+            .filter(|(_, shown)| **shown)
+            .map(|(constraint, _)| constraint);
+
+        Layout::new(self.direction, constraints)
+            .spacing(self.spacing)
+            .flex(self.flex)
+    }
+
+    /// Returns whether each child is shown, hiding children by priority until every child left
+    /// reaches its minimum size.
+    fn shown(&self, area: Rect) -> Vec<bool> {
+        let mut shown = vec![true; self.children.constraints().len()];
+
+        if self.hiding.is_empty() {
+            return shown;
+        }
+
+        loop {
+            let rects = self.split_shown(area, &shown);
+            let length = |rect: Rect| match self.direction {
+                Direction::Horizontal => rect.width,
+                Direction::Vertical => rect.height,
+            };
+            let too_small = self.hiding.iter()
+                .any(|hiding| shown.get(hiding.index) == Some(&true) && length(rects[hiding.index]) < hiding.min);
+            let lowest = self.hiding.iter()
+                .filter(|hiding| shown.get(hiding.index) == Some(&true))
+                .min_by_key(|hiding| hiding.priority);
+
+            match lowest {
+                Some(hiding) if too_small => shown[hiding.index] = false,
+                _ => return shown,
+            }
+        }
+    }
+
+    fn split_shown(&self, area: Rect, shown: &[bool]) -> Rc<[Rect]> {
+        let rects = self.layout(shown)
+            .split(area);
+        let mut rects = rects.iter().copied();
+
+        shown.iter()
+            .map(|shown| match shown {
+                true => rects.next().unwrap_or_default(),
+                false => Rect::new(area.x, area.y, 0, 0),
+            })
+            .collect()
+    }
+
+    fn split(&self, area: Rect) -> Rc<[Rect]> {
+        self.split_shown(area, &self.shown(area))
+    }
+
+    fn render_dividers(&self, area: Rect, shown: &[bool], buffer: &mut Buffer) {
+        let Some(divider) = self.divider else {
+            return;
+        };
+
+        let (_, spacers) = self.layout(shown)
+            .split_with_spacers(area);
+        let between = spacers.len().saturating_sub(1);
+
+        for spacer in spacers.iter().take(between).skip(1) {
+            let (line, symbol) = match self.direction {
+                Direction::Horizontal => (
+                    Rect { x: spacer.x + spacer.width.saturating_sub(1) / 2, width: spacer.width.min(1), ..*spacer },
+                    divider.vertical,
+                ),
+                Direction::Vertical => (
+                    Rect { y: spacer.y + spacer.height.saturating_sub(1) / 2, height: spacer.height.min(1), ..*spacer },
+                    divider.horizontal,
+                ),
+            };
+
+            for position in line.positions() {
+                buffer[position].set_symbol(symbol);
+            }
+        }
+    }
+}
+
+impl <Children: WidgetChildren> Widget for Split<Children> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let shown = self.shown(area);
+        let rects = self.split_shown(area, &shown);
+
+        self.render_dividers(area, &shown, buffer);
+        self.children.render(&rects, buffer);
+    }
+}
+
+impl <Children: RenderRefChildren> Widget for &Split<Children> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let shown = self.shown(area);
+        let rects = self.split_shown(area, &shown);
+
+        self.render_dividers(area, &shown, buffer);
+        self.children.render_ref(&rects, buffer);
+    }
+}
+
+impl <Children: StatefulWidgetChildren> StatefulWidget for Split<Children> {
+    type State = Children::State;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        let shown = self.shown(area);
+        let rects = self.split_shown(area, &shown);
+
+        self.render_dividers(area, &shown, buffer);
+        self.children.render(&rects, buffer, state);
+    }
+}
+
+impl <Id, Children: PaneChildren<Id>> Panes<Id> for Split<Children> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        let rects = self.split(area);
+
+        self.children.pane_areas(&rects, path, areas);
+    }
+}
+
+impl <Children: RenderPaneChildren> RenderPane for Split<Children> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        let shown = match path.is_empty() {
+            true => {
+                let shown = self.shown(area);
+
+                self.render_dividers(area, &shown, buffer);
+
+                shown
+            },
+            false => Vec::new(),
+        };
+
+        render_child_panes(&self.children, || self.split_shown(area, &shown), path, area, buffer)
+    }
+}
+
+impl <'a, Id, Children> IntoNode<'a, Id> for Split<Children>
+where
+    Self: PaneTree<Id> + 'a,
+{
+    fn into_node(self) -> Node<'a, Id> {
+        Node::tree(self)
+    }
+}
+
+impl <Children> From<RowsN<Children>> for Split<Children> {
+    fn from(rows: RowsN<Children>) -> Self {
+        Self::rows(rows.0)
+    }
+}
+
+impl <Children> From<ColumnsN<Children>> for Split<Children> {
+    fn from(columns: ColumnsN<Children>) -> Self {
+        Self::columns(columns.0)
+    }
+}
+
+impl <TopContent, BottomContent, Sizes: PairConstraints> From<Rows<TopContent, BottomContent, Sizes>> for Split<((TopContent, Constraint), (BottomContent, Constraint))> {
+    fn from(rows: Rows<TopContent, BottomContent, Sizes>) -> Self {
+        let [top, bottom] = rows.2.constraints();
+
+        Self::rows(((rows.0, top), (rows.1, bottom)))
+    }
+}
+
+impl <LeftContent, RightContent, Sizes: PairConstraints> From<Columns<LeftContent, RightContent, Sizes>> for Split<((LeftContent, Constraint), (RightContent, Constraint))> {
+    fn from(columns: Columns<LeftContent, RightContent, Sizes>) -> Self {
+        let [left, right] = columns.2.constraints();
+
+        Self::columns(((columns.0, left), (columns.1, right)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::widgets::Paragraph;
+
+    use super::*;
+
+    #[test]
+    fn spaces_children() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 1));
+
+        Split::columns([
+            (Paragraph::new("aaa"), Constraint::Fill(1)),
+            (Paragraph::new("bbb"), Constraint::Fill(1)),
+        ])
+            .spacing(3)
+            .render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["aaa   bbb"]));
+    }
+
+    #[test]
+    fn draws_dividers() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 5));
+
+        Split::from(Rows(
+            Paragraph::new("top"),
+            Split::from(ColumnsN((
+                (Paragraph::new("a"), Constraint::Length(1)),
+                (Paragraph::new("b"), Constraint::Length(1)),
+                (Paragraph::new("c"), Constraint::Fill(1)),
+            ))).divider(line::NORMAL),
+            Constraint::Length(1),
+        ))
+            .spacing(3)
+            .divider(line::DOUBLE)
+            .render(buffer.area, &mut buffer);
+
+        let expected_buffer = Buffer::with_lines(vec![
+            "top    ",
+            "       ",
+            "═══════",
+            "       ",
+            "a│b│c  ",
+        ]);
+
+        assert_eq!(buffer, expected_buffer);
+    }
+
+    #[test]
+    fn packs_children() {
+        let render = |flex: Flex| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+
+            Split::from(ColumnsN([
+                (Paragraph::new("aa"), Constraint::Length(2)),
+                (Paragraph::new("bb"), Constraint::Length(2)),
+            ]))
+                .flex(flex)
+                .spacing(1)
+                .render(buffer.area, &mut buffer);
+
+            buffer
+        };
+
+        assert_eq!(render(Flex::Start), Buffer::with_lines(["aa bb   "]));
+        assert_eq!(render(Flex::End), Buffer::with_lines(["   aa bb"]));
+        assert_eq!(render(Flex::SpaceBetween), Buffer::with_lines(["aa    bb"]));
+    }
+
+    #[test]
+    fn packs_pairs() {
+        let render = |sizes: (Constraint, Constraint)| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+
+            Split::from(Columns(Paragraph::new("aa"), Paragraph::new("bb"), sizes))
+                .flex(Flex::End)
+                .render(buffer.area, &mut buffer);
+
+            buffer
+        };
+
+        assert_eq!(render((Constraint::Length(2), Constraint::Length(2))), Buffer::with_lines(["    aabb"]));
+        assert_eq!(render((Constraint::Length(2), Constraint::Fill(1))), Buffer::with_lines(["aabb    "]));
+    }
+
+    #[test]
+    fn hides_by_priority() {
+        let render = |width: u16| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, width, 1));
+
+            Split::columns([
+                (Paragraph::new("aaa"), Constraint::Length(3)),
+                (Paragraph::new("bbb"), Constraint::Fill(1)),
+                (Paragraph::new("ccc"), Constraint::Length(3)),
+            ])
+                .spacing(1)
+                .hide_below(0, 3, 0)
+                .hide_below(1, 3, 2)
+                .hide_below(2, 3, 1)
+                .render(buffer.area, &mut buffer);
+
+            buffer
+        };
+
+        assert_eq!(render(11), Buffer::with_lines(["aaa bbb ccc"]));
+        assert_eq!(render(8), Buffer::with_lines(["bbb  ccc"]));
+        assert_eq!(render(5), Buffer::with_lines(["bbb  "]));
+    }
+}
@@ -0,0 +1,498 @@
+use std::rc::Rc;
+
+use ratatui::{buffer::Buffer, layout::{Constraint, Direction, Layout, Rect}, widgets::{StatefulWidget, Widget}};
+
+use crate::{panes::child_pane_areas, zoom::render_child_panes, IntoNode, Node, PanePath, PaneAreas, Panes, RenderPane, RenderRef};
+
+/// Children of a [`RowsN`] or [`ColumnsN`], each paired with its own constraint.
+///
+/// Implemented for tuples of up to twelve `(widget, constraint)` pairs, which may hold
+/// different widget types, and for arrays and `Vec`s of pairs holding the same widget type.
+pub trait SplitChildren {
+    /// Returns the constraint of every child, in order.
+    fn constraints(&self) -> Vec<Constraint>;
+}
+
+/// [`SplitChildren`] that are all [`Widget`]s.
+pub trait WidgetChildren: SplitChildren {
+    /// Renders every child into the area at the same index.
+    fn render(self, areas: &[Rect], buffer: &mut Buffer);
+}
+
+/// [`SplitChildren`] that can all be rendered by reference with [`RenderRef`](crate::RenderRef).
+pub trait RenderRefChildren: SplitChildren {
+    /// Renders every child by reference into the area at the same index.
+    fn render_ref(&self, areas: &[Rect], buffer: &mut Buffer);
+}
+
+/// [`SplitChildren`] that are all [`StatefulWidget`]s.
+///
+/// The state is a tuple, array or `Vec` holding the state of each child, in order.
+///
+/// # Panics
+///
+/// Rendering `Vec` children panics if the state `Vec` doesn't hold one state per child.
+pub trait StatefulWidgetChildren: SplitChildren {
+    /// The state of every child.
+    type State;
+
+    /// Renders every child into the area at the same index with its own state.
+    fn render(self, areas: &[Rect], buffer: &mut Buffer, state: &mut Self::State);
+}
+
+/// [`SplitChildren`] that all implement [`Panes`].
+pub trait PaneChildren<Id>: SplitChildren {
+    /// Adds the panes of every child, given the area at the same index.
+    fn pane_areas(&self, rects: &[Rect], path: &mut PanePath, areas: &mut PaneAreas<Id>);
+}
+
+/// [`SplitChildren`] that all implement [`IntoNode`].
+pub trait NodeChildren<'a, Id = ()>: SplitChildren {
+    /// Converts every child into a [`Node`] paired with its constraint.
+    fn into_nodes(self) -> Vec<(Constraint, Node<'a, Id>)>;
+}
+
+/// [`SplitChildren`] that all implement [`RenderPane`].
+pub trait RenderPaneChildren: SplitChildren {
+    /// Renders the pane or split at the given path inside the child at the given index.
+    fn render_pane(&self, index: usize, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool;
+}
+
+macro_rules! impl_split_children {
+    ($($child:ident $index:tt),+) => {
+        impl <$($child),+> SplitChildren for ($(($child, Constraint),)+) {
+            fn constraints(&self) -> Vec<Constraint> {
+                vec![$(self.$index.1),+]
+            }
+        }
+
+        impl <$($child: Widget),+> WidgetChildren for ($(($child, Constraint),)+) {
+            fn render(self, areas: &[Rect], buffer: &mut Buffer) {
+                $(self.$index.0.render(areas[$index], buffer);)+
+            }
+        }
+
+        impl <$($child: RenderRef),+> RenderRefChildren for ($(($child, Constraint),)+) {
+            fn render_ref(&self, areas: &[Rect], buffer: &mut Buffer) {
+                $(self.$index.0.render_ref(areas[$index], buffer);)+
+            }
+        }
+
+        impl <Id, $($child: Panes<Id>),+> PaneChildren<Id> for ($(($child, Constraint),)+) {
+            fn pane_areas(&self, rects: &[Rect], path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+                $(child_pane_areas(&self.$index.0, $index, rects[$index], path, areas);)+
+            }
+        }
+
+        impl <'a, Id, $($child: IntoNode<'a, Id>),+> NodeChildren<'a, Id> for ($(($child, Constraint),)+) {
+            fn into_nodes(self) -> Vec<(Constraint, Node<'a, Id>)> {
+                vec![$((self.$index.1, self.$index.0.into_node())),+]
+            }
+        }
+
+        impl <$($child: RenderPane),+> RenderPaneChildren for ($(($child, Constraint),)+) {
+            fn render_pane(&self, index: usize, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+                match index {
+                    $($index => self.$index.0.render_pane(path, area, buffer),)+
+                    _ => false,
+                }
+            }
+        }
+
+        impl <$($child: StatefulWidget),+> StatefulWidgetChildren for ($(($child, Constraint),)+) {
+            type State = ($($child::State,)+);
+
+            fn render(self, areas: &[Rect], buffer: &mut Buffer, state: &mut Self::State) {
+                $(self.$index.0.render(areas[$index], buffer, &mut state.$index);)+
+            }
+        }
+    };
+}
+
+impl_split_children!(A 0);
+impl_split_children!(A 0, B 1);
+impl_split_children!(A 0, B 1, C 2);
+impl_split_children!(A 0, B 1, C 2, D 3);
+impl_split_children!(A 0, B 1, C 2, D 3, E 4);
+impl_split_children!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_split_children!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_split_children!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_split_children!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_split_children!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_split_children!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_split_children!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+impl <Content, const N: usize> SplitChildren for [(Content, Constraint); N] {
+    fn constraints(&self) -> Vec<Constraint> {
+        self.iter()
+            .map(|(_, constraint)| *constraint)
+            .collect()
+    }
+}
+
+impl <Content: Widget, const N: usize> WidgetChildren for [(Content, Constraint); N] {
+    fn render(self, areas: &[Rect], buffer: &mut Buffer) {
+        for ((child, _), area) in self.into_iter().zip(areas) {
+            child.render(*area, buffer);
+        }
+    }
+}
+
+impl <Content: RenderRef, const N: usize> RenderRefChildren for [(Content, Constraint); N] {
+    fn render_ref(&self, areas: &[Rect], buffer: &mut Buffer) {
+        for ((child, _), area) in self.iter().zip(areas) {
+            child.render_ref(*area, buffer);
+        }
+    }
+}
+
+impl <Id, Content: Panes<Id>, const N: usize> PaneChildren<Id> for [(Content, Constraint); N] {
+    fn pane_areas(&self, rects: &[Rect], path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        for (index, ((child, _), area)) in self.iter().zip(rects).enumerate() {
+            child_pane_areas(child, index, *area, path, areas);
+        }
+    }
+}
+
+impl <'a, Id, Content: IntoNode<'a, Id>, const N: usize> NodeChildren<'a, Id> for [(Content, Constraint); N] {
+    fn into_nodes(self) -> Vec<(Constraint, Node<'a, Id>)> {
+        self.into_iter()
+            .map(|(child, constraint)| (constraint, child.into_node()))
+            .collect()
+    }
+}
+
+impl <Content: RenderPane, const N: usize> RenderPaneChildren for [(Content, Constraint); N] {
+    fn render_pane(&self, index: usize, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        self.get(index)
+            .is_some_and(|(child, _)| child.render_pane(path, area, buffer))
+    }
+}
+
+impl <Content: StatefulWidget, const N: usize> StatefulWidgetChildren for [(Content, Constraint); N] {
+    type State = [Content::State; N];
+
+    fn render(self, areas: &[Rect], buffer: &mut Buffer, state: &mut Self::State) {
+        for (((child, _), area), state) in self.into_iter().zip(areas).zip(state) {
+            child.render(*area, buffer, state);
+        }
+    }
+}
+
+impl <Content> SplitChildren for Vec<(Content, Constraint)> {
+    fn constraints(&self) -> Vec<Constraint> {
+        self.iter()
+            .map(|(_, constraint)| *constraint)
+            .collect()
+    }
+}
+
+impl <Content: Widget> WidgetChildren for Vec<(Content, Constraint)> {
+    fn render(self, areas: &[Rect], buffer: &mut Buffer) {
+        for ((child, _), area) in self.into_iter().zip(areas) {
+            child.render(*area, buffer);
+        }
+    }
+}
+
+impl <Content: RenderRef> RenderRefChildren for Vec<(Content, Constraint)> {
+    fn render_ref(&self, areas: &[Rect], buffer: &mut Buffer) {
+        for ((child, _), area) in self.iter().zip(areas) {
+            child.render_ref(*area, buffer);
+        }
+    }
+}
+
+impl <Id, Content: Panes<Id>> PaneChildren<Id> for Vec<(Content, Constraint)> {
+    fn pane_areas(&self, rects: &[Rect], path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        for (index, ((child, _), area)) in self.iter().zip(rects).enumerate() {
+            child_pane_areas(child, index, *area, path, areas);
+        }
+    }
+}
+
+impl <'a, Id, Content: IntoNode<'a, Id>> NodeChildren<'a, Id> for Vec<(Content, Constraint)> {
+    fn into_nodes(self) -> Vec<(Constraint, Node<'a, Id>)> {
+        self.into_iter()
+            .map(|(child, constraint)| (constraint, child.into_node()))
+            .collect()
+    }
+}
+
+impl <Content: RenderPane> RenderPaneChildren for Vec<(Content, Constraint)> {
+    fn render_pane(&self, index: usize, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        self.get(index)
+            .is_some_and(|(child, _)| child.render_pane(path, area, buffer))
+    }
+}
+
+impl <Content: StatefulWidget> StatefulWidgetChildren for Vec<(Content, Constraint)> {
+    type State = Vec<Content::State>;
+
+    fn render(self, areas: &[Rect], buffer: &mut Buffer, state: &mut Self::State) {
+        assert_eq!(state.len(), self.len(), "expected one state per child");
+
+        for (((child, _), area), state) in self.into_iter().zip(areas).zip(state) {
+            child.render(*area, buffer, state);
+        }
+    }
+}
+
+fn split<Children: SplitChildren>(children: &Children, direction: Direction, area: Rect) -> Rc<[Rect]> {
+    Layout::new(direction, children.constraints())
+        .split(area)
+}
+
+/// Creates any number of rows, each with its own constraint.
+///
+/// Unlike nesting [`Rows`](crate::Rows), every constraint is solved by a single [`Layout`].
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Block, Paragraph, Widget}};
+/// use ratcl::{ColumnsN, RowsN};
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         let some_block = Block::bordered();
+///         let some_paragraph = Paragraph::new("Test")
+///             .block(some_block.clone());
+///
+///         RowsN((
+///             (some_paragraph.clone(), Constraint::Length(3)),
+///             (ColumnsN([
+///                 (some_block.clone(), Constraint::Fill(1)),
+///                 (some_block, Constraint::Fill(2)),
+///             ]), Constraint::Fill(1)),
+///             (some_paragraph, Constraint::Length(3)),
+///         )).render(area, buffer);
+///     }
+/// }
+/// ```
+pub struct RowsN<Children>(pub Children);
+
+impl <Children: WidgetChildren> Widget for RowsN<Children> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let rects = split(&self.0, Direction::Vertical, area);
+
+        self.0.render(&rects, buffer);
+    }
+}
+
+impl <Children: RenderRefChildren> Widget for &RowsN<Children> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let rects = split(&self.0, Direction::Vertical, area);
+
+        self.0.render_ref(&rects, buffer);
+    }
+}
+
+impl <Children: StatefulWidgetChildren> StatefulWidget for RowsN<Children> {
+    type State = Children::State;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        let rects = split(&self.0, Direction::Vertical, area);
+
+        self.0.render(&rects, buffer, state);
+    }
+}
+
+impl <Id, Children: PaneChildren<Id>> Panes<Id> for RowsN<Children> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        let rects = split(&self.0, Direction::Vertical, area);
+
+        self.0.pane_areas(&rects, path, areas);
+    }
+}
+
+impl <Children: RenderPaneChildren> RenderPane for RowsN<Children> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        render_child_panes(&self.0, || split(&self.0, Direction::Vertical, area), path, area, buffer)
+    }
+}
+
+impl <'a, Id, Children: NodeChildren<'a, Id>> IntoNode<'a, Id> for RowsN<Children> {
+    fn into_node(self) -> Node<'a, Id> {
+        Node::Rows(self.0.into_nodes())
+    }
+}
+
+impl <'a, Id, Children: NodeChildren<'a, Id>> From<RowsN<Children>> for Node<'a, Id> {
+    fn from(rows: RowsN<Children>) -> Self {
+        rows.into_node()
+    }
+}
+
+/// Creates any number of columns, each with its own constraint.
+///
+/// Unlike nesting [`Columns`](crate::Columns), every constraint is solved by a single [`Layout`].
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Block, Paragraph, Widget}};
+/// use ratcl::ColumnsN;
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         let some_block = Block::bordered();
+///         let some_paragraph = Paragraph::new("Test")
+///             .block(some_block.clone());
+///
+///         ColumnsN((
+///             (some_block.clone(), Constraint::Length(20)),
+///             (some_paragraph, Constraint::Fill(1)),
+///             (some_block, Constraint::Percentage(25)),
+///         )).render(area, buffer);
+///     }
+/// }
+/// ```
+pub struct ColumnsN<Children>(pub Children);
+
+impl <Children: WidgetChildren> Widget for ColumnsN<Children> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let rects = split(&self.0, Direction::Horizontal, area);
+
+        self.0.render(&rects, buffer);
+    }
+}
+
+impl <Children: RenderRefChildren> Widget for &ColumnsN<Children> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let rects = split(&self.0, Direction::Horizontal, area);
+
+        self.0.render_ref(&rects, buffer);
+    }
+}
+
+impl <Children: StatefulWidgetChildren> StatefulWidget for ColumnsN<Children> {
+    type State = Children::State;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        let rects = split(&self.0, Direction::Horizontal, area);
+
+        self.0.render(&rects, buffer, state);
+    }
+}
+
+impl <Id, Children: PaneChildren<Id>> Panes<Id> for ColumnsN<Children> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        let rects = split(&self.0, Direction::Horizontal, area);
+
+        self.0.pane_areas(&rects, path, areas);
+    }
+}
+
+impl <Children: RenderPaneChildren> RenderPane for ColumnsN<Children> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        render_child_panes(&self.0, || split(&self.0, Direction::Horizontal, area), path, area, buffer)
+    }
+}
+
+impl <'a, Id, Children: NodeChildren<'a, Id>> IntoNode<'a, Id> for ColumnsN<Children> {
+    fn into_node(self) -> Node<'a, Id> {
+        Node::Columns(self.0.into_nodes())
+    }
+}
+
+impl <'a, Id, Children: NodeChildren<'a, Id>> From<ColumnsN<Children>> for Node<'a, Id> {
+    fn from(columns: ColumnsN<Children>) -> Self {
+        columns.into_node()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::widgets::{List, ListState, Paragraph};
+
+    use crate::EmptyWidget;
+
+    use super::*;
+
+    #[test]
+    fn creates_n_rows() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 6));
+
+        RowsN((
+            (Paragraph::new("One"), Constraint::Length(1)),
+            (EmptyWidget, Constraint::Fill(1)),
+            (Paragraph::new("Two"), Constraint::Length(2)),
+            (Paragraph::new("Three"), Constraint::Length(1)),
+        )).render(buffer.area, &mut buffer);
+
+        let expected_buffer = Buffer::with_lines(vec![
+            "One  ",
+            "     ",
+            "     ",
+            "Two  ",
+            "     ",
+            "Three",
+        ]);
+
+        assert_eq!(buffer, expected_buffer);
+    }
+
+    #[test]
+    fn creates_n_columns() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+
+        ColumnsN(vec![
+            (Paragraph::new("aaa"), Constraint::Length(3)),
+            (Paragraph::new("bbbbbb"), Constraint::Fill(1)),
+            (Paragraph::new("cc"), Constraint::Length(2)),
+        ]).render(buffer.area, &mut buffer);
+
+        let expected_buffer = Buffer::with_lines(vec![
+            "aaabbbbbb cc",
+        ]);
+
+        assert_eq!(buffer, expected_buffer);
+    }
+
+    #[test]
+    fn renders_stateful_children() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 2));
+        let list = List::new(["One", "Two"])
+            .highlight_symbol(">");
+
+        let mut states = [ListState::default(), ListState::default().with_selected(Some(0)), ListState::default()];
+
+        StatefulWidget::render(
+            ColumnsN([
+                (list.clone(), Constraint::Length(3)),
+                (list.clone(), Constraint::Length(3)),
+                (list, Constraint::Length(3)),
+            ]),
+            buffer.area,
+            &mut buffer,
+            &mut states,
+        );
+
+        let expected_buffer = Buffer::with_lines(vec![
+            "One>OnOne",
+            "Two TwTwo",
+        ]);
+
+        assert_eq!(buffer, expected_buffer);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected one state per child")]
+    fn rejects_missing_states() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        let list = List::new(["One", "Two"]);
+
+        StatefulWidget::render(
+            ColumnsN(vec![
+                (list.clone(), Constraint::Length(3)),
+                (list, Constraint::Length(3)),
+            ]),
+            buffer.area,
+            &mut buffer,
+            &mut vec![ListState::default()],
+        );
+    }
+}
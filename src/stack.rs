@@ -0,0 +1,268 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    widgets::{Clear, Widget},
+};
+
+use crate::{panes::child_pane_areas, IntoNode, Node, PaneAreas, PanePath, PaneTree, Panes, RenderPane, RenderRef};
+
+/// Where a widget is placed inside a larger area.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Anchor {
+    #[default]
+    Center,
+    Top,
+    Bottom,
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Anchor {
+    /// Returns how the width and the height are placed.
+    fn flex(self) -> (Flex, Flex) {
+        match self {
+            Self::Center => (Flex::Center, Flex::Center),
+            Self::Top => (Flex::Center, Flex::Start),
+            Self::Bottom => (Flex::Center, Flex::End),
+            Self::Left => (Flex::Start, Flex::Center),
+            Self::Right => (Flex::End, Flex::Center),
+            Self::TopLeft => (Flex::Start, Flex::Start),
+            Self::TopRight => (Flex::End, Flex::Start),
+            Self::BottomLeft => (Flex::Start, Flex::End),
+            Self::BottomRight => (Flex::End, Flex::End),
+        }
+    }
+
+    /// Places an area of the given size inside another.
+    pub(crate) fn area(self, area: Rect, width: Constraint, height: Constraint) -> Rect {
+        let (horizontal, vertical) = self.flex();
+        let [column] = Layout::horizontal([width])
+            .flex(horizontal)
+            .areas(area);
+        let [row] = Layout::vertical([height])
+            .flex(vertical)
+            .areas(area);
+
+        Rect::new(column.x, row.y, column.width, row.height)
+    }
+}
+
+/// A widget drawn on top of the base of a [`Stack`].
+///
+/// The `Id` is that of the [`Pane`](crate::Pane)s inside it.
+pub struct Layer<'a, Id = ()> {
+    content: Box<dyn PaneTree<Id> + 'a>,
+    anchor: Anchor,
+    width: Constraint,
+    height: Constraint,
+    clear: bool,
+}
+
+impl <'a, Id> Layer<'a, Id> {
+    /// Creates a layer filling the whole area.
+    pub fn new<Content: PaneTree<Id> + 'a>(content: Content) -> Self {
+        Self {
+            content: Box::new(content),
+            anchor: Anchor::Center,
+            width: Constraint::Fill(1),
+            height: Constraint::Fill(1),
+            clear: false,
+        }
+    }
+
+    /// Sets where the layer is placed. Defaults to the center.
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the width of the layer.
+    pub fn width<Width: Into<Constraint>>(mut self, width: Width) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the layer.
+    pub fn height<Height: Into<Constraint>>(mut self, height: Height) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Clears whatever is under the layer before drawing it.
+    pub fn clear(mut self) -> Self {
+        self.clear = true;
+        self
+    }
+
+    fn area(&self, area: Rect) -> Rect {
+        self.anchor.area(area, self.width, self.height)
+    }
+
+    fn render_ref(&self, area: Rect, buffer: &mut Buffer) {
+        let area = self.area(area);
+
+        if self.clear {
+            Clear.render(area, buffer);
+        }
+
+        self.content.render_ref(area, buffer);
+    }
+}
+
+/// Draws layers, such as popups and toasts, on top of a base widget.
+///
+/// Layers are drawn in the order they are added, so the last one ends up on top. The `Id` is that
+/// of the [`Pane`](crate::Pane)s in the base and the layers.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Block, Paragraph, Widget}};
+/// use ratcl::{Anchor, Columns, Layer, Stack};
+///
+/// struct SomeStruct;
+///
+/// impl Widget for SomeStruct {
+///     fn render(self, area: Rect, buffer: &mut Buffer) {
+///         let stack: Stack<_> = Stack::new(Columns(
+///             Paragraph::new("Sidebar"),
+///             Paragraph::new("Body"),
+///             Constraint::Length(20),
+///         ))
+///             .layer(Layer::new(Paragraph::new("Quit?").block(Block::bordered()))
+///                 .width(Constraint::Percentage(50))
+///                 .height(Constraint::Length(3))
+///                 .clear())
+///             .layer(Layer::new("Saved")
+///                 .anchor(Anchor::BottomRight)
+///                 .width(Constraint::Length(5))
+///                 .height(Constraint::Length(1)));
+///
+///         stack.render(area, buffer);
+///     }
+/// }
+/// ```
+pub struct Stack<'a, Base, Id = ()> {
+    base: Base,
+    layers: Vec<Layer<'a, Id>>,
+}
+
+impl <'a, Base, Id> Stack<'a, Base, Id> {
+    /// Creates a stack with no layers over the given base.
+    pub fn new(base: Base) -> Self {
+        Self {
+            base,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Adds a layer on top of the others.
+    pub fn layer(mut self, layer: Layer<'a, Id>) -> Self {
+        self.layers.push(layer);
+        self
+    }
+}
+
+impl <Base: Widget, Id> Widget for Stack<'_, Base, Id> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        self.base.render(area, buffer);
+
+        for layer in &self.layers {
+            layer.render_ref(area, buffer);
+        }
+    }
+}
+
+impl <Base: RenderRef, Id> Widget for &Stack<'_, Base, Id> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        self.base.render_ref(area, buffer);
+
+        for layer in &self.layers {
+            layer.render_ref(area, buffer);
+        }
+    }
+}
+
+/// The base is the first child and the layers follow, so [`PaneAreas::hit`] finds the topmost
+/// layer first.
+impl <Id, Base: Panes<Id>> Panes<Id> for Stack<'_, Base, Id> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<Id>) {
+        child_pane_areas(&self.base, 0, area, path, areas);
+
+        for (index, layer) in self.layers.iter().enumerate() {
+            child_pane_areas(&*layer.content, index + 1, layer.area(area), path, areas);
+        }
+    }
+}
+
+impl <Base: RenderPane, Id> RenderPane for Stack<'_, Base, Id> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        match path.split_first() {
+            None => {
+                self.base.render_pane(path, area, buffer);
+
+                for layer in &self.layers {
+                    layer.render_ref(area, buffer);
+                }
+
+                true
+            },
+            Some((0, path)) => self.base.render_pane(path, area, buffer),
+            Some((index, path)) => self.layers.get(index - 1)
+                .is_some_and(|layer| layer.content.render_pane(path, area, buffer)),
+        }
+    }
+}
+
+impl <'a, Id, Base> IntoNode<'a, Id> for Stack<'a, Base, Id>
+where
+    Self: PaneTree<Id> + 'a,
+{
+    fn into_node(self) -> Node<'a, Id> {
+        Node::tree(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::widgets::Paragraph;
+
+    use crate::{Columns, Pane};
+
+    use super::*;
+
+    #[test]
+    fn draws_anchored_layers() {
+        let stack: Stack<_> = Stack::new(Paragraph::new(vec!["abcdefg".into(); 3]))
+            .layer(Layer::new("x")
+                .width(Constraint::Length(3))
+                .height(Constraint::Length(1))
+                .clear())
+            .layer(Layer::new("y")
+                .anchor(Anchor::BottomRight)
+                .width(Constraint::Length(1))
+                .height(Constraint::Length(1)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+
+        stack.render_ref(buffer.area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["abcdefg", "abx  fg", "abcdefy"]));
+        assert_eq!(PaneAreas::of(&stack, buffer.area).hit((6, 2)).map(|pane| pane.path.clone()), Some(vec![2]));
+    }
+
+    #[test]
+    fn keeps_layer_pane_ids() {
+        let stack = Stack::new(Pane("base", "abc"))
+            .layer(Layer::new(Columns(Pane("yes", "y"), Pane("no", "n"), Constraint::Length(1)))
+                .anchor(Anchor::Bottom)
+                .height(Constraint::Length(1)));
+        let areas = PaneAreas::of(&stack, Rect::new(0, 0, 3, 2));
+
+        assert_eq!(areas.get(&"base"), Some(Rect::new(0, 0, 3, 2)));
+        assert_eq!(areas.get(&"no"), Some(Rect::new(1, 1, 2, 1)));
+        assert_eq!(areas.hit((0, 1)).and_then(|pane| pane.id), Some("yes"));
+    }
+}
@@ -0,0 +1,224 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    text::Line,
+    widgets::{StatefulWidget, Tabs, Widget},
+};
+
+use crate::{Panes, RenderRef};
+
+/// Which side of a [`Tabbed`] container the tab bar is drawn on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TabBar {
+    #[default]
+    Top,
+    Bottom,
+}
+
+/// The selected tab of a [`Tabbed`] container, and how many tabs it was last rendered with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TabbedState {
+    selected: usize,
+    count: usize,
+}
+
+impl TabbedState {
+    /// Creates a state with the first tab selected.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index of the selected tab.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Selects the tab at the given index.
+    ///
+    /// The index is kept inside the tabs when rendering.
+    pub fn select(&mut self, index: usize) {
+        self.selected = index;
+    }
+
+    /// Selects the next tab, wrapping around to the first, as of the last render.
+    pub fn select_next(&mut self) {
+        if self.count > 0 {
+            self.selected = (self.selected + 1) % self.count;
+        }
+    }
+
+    /// Selects the previous tab, wrapping around to the last, as of the last render.
+    pub fn select_previous(&mut self) {
+        if self.count > 0 {
+            self.selected = (self.selected + self.count - 1) % self.count;
+        }
+    }
+
+    fn update(&mut self, count: usize) {
+        self.count = count;
+        self.selected = self.selected.min(count.saturating_sub(1));
+    }
+}
+
+/// Holds several widgets, draws a tab bar with their titles and renders only the selected one
+/// in the rest of the area.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, style::{Style, Stylize}, widgets::{List, Paragraph, StatefulWidget}};
+/// use ratcl::{Columns, Stateless, TabBar, Tabbed, TabbedState};
+///
+/// struct App {
+///     state: ((), TabbedState),
+/// }
+///
+/// impl App {
+///     fn render(&mut self, area: Rect, buffer: &mut Buffer) {
+///         Columns(
+///             Stateless(Paragraph::new("Sidebar")),
+///             Tabbed::new()
+///                 .tab("Editor", Paragraph::new("fn main() {}"))
+///                 .tab("Files", List::new(["main.rs", "lib.rs"]))
+///                 .bar(TabBar::Bottom)
+///                 .highlight_style(Style::new().reversed()),
+///             Constraint::Length(20),
+///         ).render(area, buffer, &mut self.state);
+///     }
+///
+///     fn on_key(&mut self, key: char) {
+///         if key == '\t' {
+///             self.state.1.select_next();
+///         }
+///     }
+/// }
+/// ```
+pub struct Tabbed<'a> {
+    titles: Vec<Line<'a>>,
+    contents: Vec<Box<dyn RenderRef + 'a>>,
+    bar: TabBar,
+    style: Style,
+    highlight_style: Style,
+}
+
+impl <'a> Tabbed<'a> {
+    /// Creates a container with no tabs.
+    pub fn new() -> Self {
+        Self {
+            titles: Vec::new(),
+            contents: Vec::new(),
+            bar: TabBar::Top,
+            style: Style::new(),
+            highlight_style: Style::new(),
+        }
+    }
+
+    /// Adds a tab after the others.
+    pub fn tab<Title: Into<Line<'a>>, Content: RenderRef + 'a>(mut self, title: Title, content: Content) -> Self {
+        self.titles.push(title.into());
+        self.contents.push(Box::new(content));
+        self
+    }
+
+    /// Sets which side the tab bar is drawn on. Defaults to the top.
+    pub fn bar(mut self, bar: TabBar) -> Self {
+        self.bar = bar;
+        self
+    }
+
+    /// Sets the style of the tab bar.
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected title.
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Returns the areas of the tab bar and of the selected tab.
+    fn split(&self, area: Rect) -> (Rect, Rect) {
+        match self.bar {
+            TabBar::Top => {
+                let [bar, content] = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+
+                (bar, content)
+            },
+            TabBar::Bottom => {
+                let [content, bar] = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(area);
+
+                (bar, content)
+            },
+        }
+    }
+}
+
+impl Default for Tabbed<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StatefulWidget for Tabbed<'_> {
+    type State = TabbedState;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        state.update(self.contents.len());
+
+        let (bar, content) = self.split(area);
+
+        if let Some(selected) = self.contents.get(state.selected) {
+            selected.render_ref(content, buffer);
+        }
+
+        Tabs::new(self.titles)
+            .select(state.selected)
+            .style(self.style)
+            .highlight_style(self.highlight_style)
+            .render(bar, buffer);
+    }
+}
+
+impl <Id> Panes<Id> for Tabbed<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Constraint;
+
+    use crate::{Rows, Stateless};
+
+    use super::*;
+
+    #[test]
+    fn renders_selected_tab() {
+        let render = |state: &mut ((), TabbedState)| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+
+            Rows(
+                Stateless("top"),
+                Tabbed::new()
+                    .tab("a", "one")
+                    .tab("b", "two")
+                    .bar(TabBar::Bottom),
+                Constraint::Length(1),
+            ).render(buffer.area, &mut buffer, state);
+
+            buffer
+        };
+        let mut state = ((), TabbedState::new());
+
+        assert_eq!(render(&mut state), Buffer::with_lines(["top      ", "one      ", " a │ b   "]));
+
+        state.1.select_next();
+        assert_eq!(render(&mut state), Buffer::with_lines(["top      ", "two      ", " a │ b   "]));
+
+        state.1.select(5);
+        render(&mut state);
+        assert_eq!(state.1.selected(), 1);
+
+        state.1.select_next();
+        assert_eq!(state.1.selected(), 0);
+    }
+}
@@ -0,0 +1,311 @@
+use std::{error::Error, fmt};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Rect},
+    widgets::Widget,
+};
+
+use crate::{Grid, GridCell, IntoNode, Node, PaneAreas, PanePath, Panes, RenderPane, RenderRef};
+
+/// An area named in a [`Template`], covering a rectangle of its grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct NamedArea {
+    name: String,
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+}
+
+/// Why a [`Template`] could not be created or filled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateError {
+    /// The template has no rows.
+    Empty,
+    /// A row names a different number of columns than the first row.
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The number of row or column constraints does not match the template. Rows are
+    /// [`Direction::Vertical`] and columns [`Direction::Horizontal`].
+    ConstraintCount {
+        direction: Direction,
+        expected: usize,
+        found: usize,
+    },
+    /// The cells with this name do not form a rectangle.
+    NotRectangular(String),
+    /// No area has this name.
+    UnknownArea(String),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(formatter, "the template has no rows"),
+            Self::RaggedRow { row, expected, found } => write!(
+                formatter,
+                "row {row} of the template has {found} columns, but the first row has {expected}",
+            ),
+            Self::ConstraintCount { direction, expected, found } => {
+                let line = match direction {
+                    Direction::Vertical => "row",
+                    Direction::Horizontal => "column",
+                };
+
+                write!(formatter, "the template has {expected} {line}(s), but {found} {line} constraints were given")
+            },
+            Self::NotRectangular(name) => write!(formatter, "area \"{name}\" is not a rectangle"),
+            Self::UnknownArea(name) => write!(formatter, "the template has no area named \"{name}\""),
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+/// Lays out widgets by area name, with the areas drawn as rows of names.
+///
+/// Names are separated by whitespace, and every name must cover a rectangle. A `.` leaves a
+/// cell empty.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Block, Widget}};
+/// use ratcl::{Template, TemplateError};
+///
+/// fn render(area: Rect, buffer: &mut Buffer) -> Result<(), TemplateError> {
+///     Template::new(
+///         [
+///             "header header",
+///             "side   main",
+///             "footer footer",
+///         ],
+///         [Constraint::Length(3), Constraint::Fill(1), Constraint::Length(1)],
+///         [Constraint::Length(20), Constraint::Fill(1)],
+///     )?
+///         .area("header", Block::bordered().title("Header"))?
+///         .area("side", Block::bordered().title("Sidebar"))?
+///         .area("main", Block::bordered().title("Main"))?
+///         .area("footer", Block::new().title("Status"))?
+///         .render(area, buffer);
+///
+///     Ok(())
+/// }
+/// ```
+pub struct Template<'a> {
+    areas: Vec<NamedArea>,
+    grid: Grid<'a>,
+    names: Vec<String>,
+}
+
+impl <'a> Template<'a> {
+    /// Creates a template from rows of area names and the row and column constraints.
+    pub fn new<Lines, Rows, Columns>(template: Lines, rows: Rows, columns: Columns) -> Result<Self, TemplateError>
+    where
+        Lines: IntoIterator,
+        Lines::Item: AsRef<str>,
+        Rows: IntoIterator,
+        Rows::Item: Into<Constraint>,
+        Columns: IntoIterator,
+        Columns::Item: Into<Constraint>,
+    {
+        let cells = template.into_iter()
+            .map(|line| line.as_ref()
+                .split_whitespace()
+                .map(String::from)
+                .collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let rows = rows.into_iter().map(Into::into).collect::<Vec<_>>();
+        let columns = columns.into_iter().map(Into::into).collect::<Vec<_>>();
+
+        let width = cells.first()
+            .ok_or(TemplateError::Empty)?
+            .len();
+
+        if let Some((row, line)) = cells.iter().enumerate().find(|(_, line)| line.len() != width) {
+            return Err(TemplateError::RaggedRow {
+                row,
+                expected: width,
+                found: line.len(),
+            });
+        }
+
+        for (direction, expected, found) in [(Direction::Vertical, cells.len(), rows.len()), (Direction::Horizontal, width, columns.len())] {
+            if expected != found {
+                return Err(TemplateError::ConstraintCount {
+                    direction,
+                    expected,
+                    found,
+                });
+            }
+        }
+
+        Ok(Self {
+            areas: named_areas(&cells)?,
+            grid: Grid::new(rows, columns),
+            names: Vec::new(),
+        })
+    }
+
+    /// Sets the number of empty cells between neighbouring rows and columns.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.grid = self.grid.spacing(spacing);
+        self
+    }
+
+    /// Places a widget in the area with the given name.
+    pub fn area<Content: RenderRef + 'a>(mut self, name: &str, content: Content) -> Result<Self, TemplateError> {
+        let area = self.areas.iter()
+            .find(|area| area.name == name)
+            .ok_or_else(|| TemplateError::UnknownArea(name.to_string()))?;
+
+        self.grid = self.grid.cell(GridCell::new(area.row, area.column, Node::leaf(content))
+            .row_span(area.row_span)
+            .column_span(area.column_span));
+        self.names.push(name.to_string());
+
+        Ok(self)
+    }
+}
+
+/// Finds the rectangle covered by every name, checking that it is filled by that name alone.
+fn named_areas(cells: &[Vec<String>]) -> Result<Vec<NamedArea>, TemplateError> {
+    let mut areas: Vec<NamedArea> = Vec::new();
+
+    for (row, line) in cells.iter().enumerate() {
+        for (column, name) in line.iter().enumerate() {
+            if name == "." {
+                continue;
+            }
+
+            match areas.iter_mut().find(|area| area.name == *name) {
+                Some(area) => {
+                    area.row_span = area.row_span.max(row - area.row + 1);
+                    area.column_span = (area.column + area.column_span).max(column + 1) - area.column.min(column);
+                    area.column = area.column.min(column);
+                },
+                None => areas.push(NamedArea {
+                    name: name.clone(),
+                    row,
+                    column,
+                    row_span: 1,
+                    column_span: 1,
+                }),
+            }
+        }
+    }
+
+    for area in &areas {
+        let filled = cells[area.row..area.row + area.row_span].iter()
+            .all(|line| line[area.column..area.column + area.column_span].iter()
+                .all(|name| *name == area.name));
+        let count = cells.iter()
+            .flatten()
+            .filter(|name| **name == area.name)
+            .count();
+
+        if !filled || count != area.row_span * area.column_span {
+            return Err(TemplateError::NotRectangular(area.name.clone()));
+        }
+    }
+
+    Ok(areas)
+}
+
+impl Widget for Template<'_> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        self.grid.render(area, buffer);
+    }
+}
+
+impl Widget for &Template<'_> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        self.grid.render_ref(area, buffer);
+    }
+}
+
+impl Panes<String> for Template<'_> {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<String>) {
+        let mut grid_areas = PaneAreas::<()>::default();
+
+        self.grid.pane_areas(area, path, &mut grid_areas);
+
+        for pane in &grid_areas {
+            let name = pane.path.last()
+                .and_then(|index| self.names.get(*index))
+                .cloned();
+
+            areas.push(&pane.path, name, pane.area);
+        }
+    }
+}
+
+impl RenderPane for Template<'_> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        self.grid.render_pane(path, area, buffer)
+    }
+}
+
+impl <'a> IntoNode<'a, String> for Template<'a> {
+    fn into_node(self) -> Node<'a, String> {
+        Node::tree(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::widgets::Paragraph;
+
+    use super::*;
+
+    #[test]
+    fn places_named_areas() {
+        let template = Template::new(
+            ["head head .", "side main main", "side main main"],
+            [Constraint::Length(1); 3],
+            [Constraint::Length(4), Constraint::Length(2), Constraint::Fill(1)],
+        ).unwrap()
+            .area("head", Paragraph::new("Title")).unwrap()
+            .area("side", Paragraph::new("S")).unwrap()
+            .area("main", Paragraph::new("Main")).unwrap();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+
+        template.render_ref(buffer.area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["Title     ", "S   Main  ", "          "]));
+        assert_eq!(PaneAreas::of(&template, buffer.area).get(&"main".to_string()), Some(Rect::new(4, 1, 6, 2)));
+    }
+
+    #[test]
+    fn rejects_invalid_templates() {
+        let columns = [Constraint::Fill(1); 2];
+
+        assert_eq!(
+            Template::new(["a b", "b a"], [Constraint::Fill(1); 2], columns).err(),
+            Some(TemplateError::NotRectangular("a".to_string())),
+        );
+        assert_eq!(
+            Template::new(["a a", "b b", "a a"], [Constraint::Fill(1); 3], columns).err(),
+            Some(TemplateError::NotRectangular("a".to_string())),
+        );
+        assert_eq!(
+            Template::new(["a b", "c"], [Constraint::Fill(1); 2], columns).err(),
+            Some(TemplateError::RaggedRow { row: 1, expected: 2, found: 1 }),
+        );
+        assert_eq!(
+            Template::new(["a b"], [Constraint::Fill(1); 2], columns).err(),
+            Some(TemplateError::ConstraintCount { direction: Direction::Vertical, expected: 1, found: 2 }),
+        );
+        assert_eq!(
+            Template::new(["a b"], [Constraint::Fill(1); 2], columns).err().map(|error| error.to_string()),
+            Some("the template has 1 row(s), but 2 row constraints were given".to_string()),
+        );
+        assert_eq!(
+            Template::new(["a b"], [Constraint::Fill(1)], columns).unwrap().area("c", "c").err().map(|error| error.to_string()),
+            Some("the template has no area named \"c\"".to_string()),
+        );
+    }
+}
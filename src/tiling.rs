@@ -0,0 +1,459 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+
+use crate::{panes::child_pane_areas, IntoNode, Node, Pane, PaneAreas, PanePath, Panes};
+
+/// The id of a pane in a [`Tiling`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PaneId(pub usize);
+
+/// A pane or a split of a [`Tiling`].
+///
+/// Splits always hold two or more children.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Tile {
+    /// A pane, filled with a widget by [`Tiling::node`].
+    Pane(PaneId),
+    /// Children laid out in a direction, each with its constraint.
+    Split(Direction, Vec<(Constraint, Tile)>),
+}
+
+impl Tile {
+    fn get_mut(&mut self, path: &[usize]) -> Option<&mut Tile> {
+        match path.split_first() {
+            None => Some(self),
+            Some((index, rest)) => match self {
+                Self::Split(_, children) => children.get_mut(*index)?.1.get_mut(rest),
+                Self::Pane(_) => None,
+            },
+        }
+    }
+
+    fn path_of(&self, id: PaneId, path: &mut PanePath) -> bool {
+        match self {
+            Self::Pane(pane) => *pane == id,
+            Self::Split(_, children) => children.iter()
+                .enumerate()
+                .any(|(index, (_, child))| {
+                    path.push(index);
+
+                    let found = child.path_of(id, path);
+
+                    if !found {
+                        path.pop();
+                    }
+
+                    found
+                }),
+        }
+    }
+
+    fn pane_ids(&self, ids: &mut Vec<PaneId>) {
+        match self {
+            Self::Pane(id) => ids.push(*id),
+            Self::Split(_, children) => children.iter()
+                .for_each(|(_, child)| child.pane_ids(ids)),
+        }
+    }
+
+    fn panes_mut(&mut self, f: &mut impl FnMut(&mut PaneId)) {
+        match self {
+            Self::Pane(id) => f(id),
+            Self::Split(_, children) => children.iter_mut()
+                .for_each(|(_, child)| child.panes_mut(f)),
+        }
+    }
+
+    fn equalize(&mut self) {
+        if let Self::Split(_, children) = self {
+            for (constraint, child) in children {
+                *constraint = Constraint::Fill(1);
+                child.equalize();
+            }
+        }
+    }
+
+    /// Moves the children of the child split at the given index into this split, if both have
+    /// the same direction and the child split still has the default `Fill(1)` share.
+    fn join(&mut self, index: usize) {
+        let Self::Split(direction, children) = self else {
+            return;
+        };
+
+        let joins = matches!(
+            children.get(index),
+            Some((Constraint::Fill(1), Self::Split(child_direction, _))) if child_direction == direction,
+        );
+
+        if joins && let (_, Self::Split(_, grandchildren)) = children.remove(index) {
+            children.splice(index..index, grandchildren);
+        }
+    }
+
+    fn node<'a, Content: IntoNode<'a, PaneId>>(&self, content: &mut impl FnMut(PaneId) -> Content) -> Node<'a, PaneId> {
+        match self {
+            Self::Pane(id) => Node::tree(Pane(*id, content(*id).into_node())),
+            Self::Split(direction, children) => {
+                let children = children.iter()
+                    .map(|(constraint, child)| (*constraint, child.node(content)))
+                    .collect();
+
+                match direction {
+                    Direction::Horizontal => Node::Columns(children),
+                    Direction::Vertical => Node::Rows(children),
+                }
+            },
+        }
+    }
+}
+
+impl Panes<PaneId> for Tile {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<PaneId>) {
+        match self {
+            Self::Pane(id) => areas.push(path, Some(*id), area),
+            Self::Split(direction, children) => {
+                let rects = Layout::new(*direction, children.iter().map(|(constraint, _)| *constraint))
+                    .split(area);
+
+                for (index, ((_, child), area)) in children.iter().zip(rects.iter()).enumerate() {
+                    child_pane_areas(child, index, *area, path, areas);
+                }
+            },
+        }
+    }
+}
+
+/// A layout tree changed at runtime like the windows of a tiling window manager, with panes that
+/// are split, closed, swapped and resized.
+///
+/// Its panes are keyed by [`PaneId`], and [`Tiling::node`] fills them with widgets to render.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Direction, Rect}, widgets::{Block, Widget}};
+/// use ratcl::{FocusState, PaneId, Tiling};
+///
+/// struct App {
+///     tiling: Tiling,
+///     focus: FocusState<PaneId>,
+/// }
+///
+/// impl App {
+///     fn render(&mut self, area: Rect, buffer: &mut Buffer) {
+///         self.focus.update(&self.tiling, area);
+///         self.tiling
+///             .node(|id| Block::bordered().title(format!("Pane {}", id.0)))
+///             .render(area, buffer);
+///     }
+///
+///     fn on_key(&mut self, key: char) {
+///         let Some(focused) = self.focus.focused().and_then(|pane| pane.id) else {
+///             return;
+///         };
+///
+///         match key {
+///             '%' => _ = self.tiling.split(focused, Direction::Horizontal),
+///             '"' => _ = self.tiling.split(focused, Direction::Vertical),
+///             'x' => _ = self.tiling.close(focused),
+///             'r' => _ = self.tiling.rotate(focused),
+///             '=' => self.tiling.equalize(),
+///             _ => {},
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tiling {
+    root: Tile,
+    next_id: usize,
+}
+
+impl Default for Tiling {
+    fn default() -> Self {
+        Self {
+            root: Tile::Pane(PaneId(0)),
+            next_id: 1,
+        }
+    }
+}
+
+impl Tiling {
+    /// Creates a tiling with a single pane, whose id is `PaneId(0)`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the tile at the top of the tree.
+    pub fn root(&self) -> &Tile {
+        &self.root
+    }
+
+    /// Returns the id of every pane, in the order they are rendered.
+    pub fn pane_ids(&self) -> Vec<PaneId> {
+        let mut ids = Vec::new();
+
+        self.root.pane_ids(&mut ids);
+
+        ids
+    }
+
+    /// Returns the path to a pane, which is also its path in [`PaneAreas`].
+    pub fn path_of(&self, id: PaneId) -> Option<PanePath> {
+        let mut path = PanePath::new();
+
+        self.root.path_of(id, &mut path)
+            .then_some(path)
+    }
+
+    /// Splits a pane in the given direction, placing a new pane after it, and returns the id of
+    /// the new pane.
+    ///
+    /// If the pane is already in a split in that direction, the new pane joins that split.
+    pub fn split(&mut self, id: PaneId, direction: Direction) -> Option<PaneId> {
+        let path = self.path_of(id)?;
+        let new_id = PaneId(self.next_id);
+
+        self.next_id += 1;
+
+        if let Some((index, parent)) = path.split_last()
+            && let Some(Tile::Split(parent_direction, children)) = self.root.get_mut(parent)
+            && *parent_direction == direction
+        {
+            children.insert(index + 1, (Constraint::Fill(1), Tile::Pane(new_id)));
+        } else {
+            let pane = self.root.get_mut(&path)?;
+
+            *pane = Tile::Split(direction, vec![
+                (Constraint::Fill(1), Tile::Pane(id)),
+                (Constraint::Fill(1), Tile::Pane(new_id)),
+            ]);
+        }
+
+        Some(new_id)
+    }
+
+    /// Closes a pane, giving its space to its siblings.
+    ///
+    /// A split left with one child is replaced by that child, which keeps the split's constraint.
+    /// If that child is a split with the same direction as its new parent, its children join the
+    /// parent, unless it was resized and would lose its size. The last pane can't be closed.
+    pub fn close(&mut self, id: PaneId) -> bool {
+        let Some(path) = self.path_of(id) else {
+            return false;
+        };
+        let Some((index, parent_path)) = path.split_last() else {
+            return false;
+        };
+        let Some(parent) = self.root.get_mut(parent_path) else {
+            return false;
+        };
+
+        if let Tile::Split(_, children) = parent {
+            children.remove(*index);
+
+            if children.len() == 1 {
+                let (_, child) = children.remove(0);
+
+                *parent = child;
+
+                if let Some((parent_index, grandparent)) = parent_path.split_last()
+                    && let Some(grandparent) = self.root.get_mut(grandparent)
+                {
+                    grandparent.join(*parent_index);
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Swaps the places of two panes.
+    pub fn swap(&mut self, first: PaneId, second: PaneId) -> bool {
+        if self.path_of(first).is_none() || self.path_of(second).is_none() {
+            return false;
+        }
+
+        self.root.panes_mut(&mut |id| {
+            if *id == first {
+                *id = second;
+            } else if *id == second {
+                *id = first;
+            }
+        });
+
+        true
+    }
+
+    /// Turns the split holding a pane between rows and columns.
+    ///
+    /// The split is kept even when it ends up with the same direction as its parent, so rotating
+    /// it again brings back the same layout.
+    pub fn rotate(&mut self, id: PaneId) -> bool {
+        let Some(path) = self.path_of(id) else {
+            return false;
+        };
+        let Some((_, parent)) = path.split_last() else {
+            return false;
+        };
+
+        match self.root.get_mut(parent) {
+            Some(Tile::Split(direction, _)) => {
+                *direction = match direction {
+                    Direction::Horizontal => Direction::Vertical,
+                    Direction::Vertical => Direction::Horizontal,
+                };
+
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Gives the children of every split an equal share of its space.
+    pub fn equalize(&mut self) {
+        self.root.equalize();
+    }
+
+    /// Sets the constraint of a pane in its split.
+    pub fn resize(&mut self, id: PaneId, constraint: Constraint) -> bool {
+        let Some(path) = self.path_of(id) else {
+            return false;
+        };
+        let Some((index, parent)) = path.split_last() else {
+            return false;
+        };
+
+        match self.root.get_mut(parent) {
+            Some(Tile::Split(_, children)) => {
+                children[*index].0 = constraint;
+
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Creates a [`Node`] to render, filling every pane with a widget.
+    ///
+    /// Every pane of the node keeps its [`PaneId`].
+    pub fn node<'a, Content: IntoNode<'a, PaneId>>(&self, mut content: impl FnMut(PaneId) -> Content) -> Node<'a, PaneId> {
+        self.root.node(&mut content)
+    }
+}
+
+impl Panes<PaneId> for Tiling {
+    fn pane_areas(&self, area: Rect, path: &mut PanePath, areas: &mut PaneAreas<PaneId>) {
+        self.root.pane_areas(area, path, areas);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    /// Checks that every split has two or more children, that no split holds another split with
+    /// the same direction and the default share, and that no id is used twice.
+    fn assert_valid(tiling: &Tiling) {
+        fn check(tile: &Tile) {
+            if let Tile::Split(direction, children) = tile {
+                assert!(children.len() >= 2, "split with {} children", children.len());
+
+                for (constraint, child) in children {
+                    if let Tile::Split(child_direction, _) = child
+                        && *constraint == Constraint::Fill(1)
+                    {
+                        assert_ne!(child_direction, direction, "split inside a split with the same direction");
+                    }
+
+                    check(child);
+                }
+            }
+        }
+
+        check(tiling.root());
+
+        let ids = tiling.pane_ids();
+
+        assert_eq!(ids.iter().collect::<HashSet<_>>().len(), ids.len());
+    }
+
+    #[test]
+    fn splits_and_closes_panes() {
+        let mut tiling = Tiling::new();
+        let area = Rect::new(0, 0, 40, 20);
+
+        let right = tiling.split(PaneId(0), Direction::Horizontal).unwrap();
+        assert_valid(&tiling);
+        let bottom = tiling.split(right, Direction::Vertical).unwrap();
+        assert_valid(&tiling);
+        let far_right = tiling.split(PaneId(0), Direction::Horizontal).unwrap();
+        assert_valid(&tiling);
+
+        assert_eq!(tiling.pane_ids(), vec![PaneId(0), far_right, right, bottom]);
+        assert_eq!(PaneAreas::of(&tiling, area).get(&bottom), Some(Rect::new(27, 10, 13, 10)));
+
+        assert!(tiling.close(right));
+        assert_valid(&tiling);
+        assert_eq!(tiling.path_of(bottom), Some(vec![2]));
+
+        let middle = tiling.split(far_right, Direction::Vertical).unwrap();
+        let middle_right = tiling.split(middle, Direction::Horizontal).unwrap();
+        assert!(tiling.close(far_right));
+        assert_valid(&tiling);
+        tiling.equalize();
+        assert_eq!(PaneAreas::of(&tiling, area).get(&middle_right), Some(Rect::new(20, 0, 10, 20)));
+
+        assert!(tiling.close(PaneId(0)));
+        assert!(tiling.close(middle));
+        assert!(tiling.close(middle_right));
+        assert_valid(&tiling);
+        assert_eq!(tiling.root(), &Tile::Pane(bottom));
+        assert!(!tiling.close(bottom));
+        assert!(!tiling.close(right));
+    }
+
+    #[test]
+    fn keeps_resized_splits_when_closing() {
+        let mut tiling = Tiling::new();
+        let area = Rect::new(0, 0, 40, 20);
+
+        let right = tiling.split(PaneId(0), Direction::Horizontal).unwrap();
+        assert!(tiling.resize(right, Constraint::Length(10)));
+        let bottom = tiling.split(right, Direction::Vertical).unwrap();
+        let bottom_right = tiling.split(bottom, Direction::Horizontal).unwrap();
+
+        assert!(tiling.close(right));
+        assert_valid(&tiling);
+        assert_eq!(PaneAreas::of(&tiling, area).get(&bottom), Some(Rect::new(30, 0, 5, 20)));
+        assert_eq!(PaneAreas::of(&tiling, area).get(&bottom_right), Some(Rect::new(35, 0, 5, 20)));
+    }
+
+    #[test]
+    fn swaps_rotates_and_equalizes() {
+        let mut tiling = Tiling::new();
+        let area = Rect::new(0, 0, 40, 20);
+
+        let right = tiling.split(PaneId(0), Direction::Horizontal).unwrap();
+        let bottom = tiling.split(right, Direction::Vertical).unwrap();
+
+        assert!(tiling.swap(PaneId(0), bottom));
+        assert_valid(&tiling);
+        assert_eq!(PaneAreas::of(&tiling, area).get(&bottom), Some(Rect::new(0, 0, 20, 20)));
+
+        let before = tiling.clone();
+
+        assert!(tiling.rotate(right));
+        assert_eq!(PaneAreas::of(&tiling, area).get(&PaneId(0)), Some(Rect::new(30, 0, 10, 20)));
+        assert!(tiling.rotate(right));
+        assert_eq!(tiling, before);
+
+        assert!(tiling.resize(bottom, Constraint::Length(4)));
+        assert_eq!(PaneAreas::of(&tiling, area).get(&bottom), Some(Rect::new(0, 0, 4, 20)));
+
+        tiling.equalize();
+        assert_valid(&tiling);
+        assert_eq!(PaneAreas::of(&tiling, area).get(&bottom), Some(Rect::new(0, 0, 20, 20)));
+        assert!(!tiling.rotate(PaneId(9)));
+    }
+}
@@ -0,0 +1,279 @@
+use std::rc::Rc;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::{Line, Span, Text},
+    widgets::{
+        canvas::{Canvas, Context},
+        BarChart, Block, Chart, Clear, Gauge, LineGauge, List, Paragraph, Sparkline, StatefulWidget,
+        Table, Tabs,
+    },
+};
+
+use crate::{EmptyWidget, Pane, PanePath, RenderPaneChildren, RenderRef};
+
+/// A layout tree that can render one of its panes or splits on its own, filling the whole area.
+///
+/// Paths are the same as in [`PaneAreas`](crate::PaneAreas). Every widget is a leaf, so
+/// implementing this for your own widgets only takes rendering them for the empty path:
+///
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+/// use ratcl::RenderPane;
+///
+/// struct SomeStruct;
+///
+/// impl Widget for &SomeStruct {
+///     fn render(self, _: Rect, _: &mut Buffer) {}
+/// }
+///
+/// impl RenderPane for SomeStruct {
+///     fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+///         if !path.is_empty() {
+///             return false;
+///         }
+///
+///         self.render(area, buffer);
+///
+///         true
+///     }
+/// }
+/// ```
+pub trait RenderPane {
+    /// Renders the pane or split at the given path, without the rest of the tree. The empty path
+    /// renders the whole tree.
+    ///
+    /// Returns `false`, having rendered nothing, if there is nothing at the path.
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool;
+}
+
+/// Renders a leaf widget if the path is empty, as it has no children.
+pub(crate) fn render_leaf<Leaf: RenderRef + ?Sized>(leaf: &Leaf, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+    if !path.is_empty() {
+        return false;
+    }
+
+    leaf.render_ref(area, buffer);
+
+    true
+}
+
+/// Renders the pane or split at the path inside one of the children of a split, or every child
+/// into its own area if the path is empty.
+pub(crate) fn render_child_panes<Children: RenderPaneChildren>(
+    children: &Children,
+    split: impl FnOnce() -> Rc<[Rect]>,
+    path: &[usize],
+    area: Rect,
+    buffer: &mut Buffer,
+) -> bool {
+    let Some((index, path)) = path.split_first() else {
+        for (index, area) in split().iter().enumerate() {
+            children.render_pane(index, &[], *area, buffer);
+        }
+
+        return true;
+    };
+
+    children.render_pane(*index, path, area, buffer)
+}
+
+/// Which pane or split of a layout tree is zoomed to fill the whole area.
+///
+/// The layout tree is left as it is, so unzooming brings back every split at its old size.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ZoomState {
+    zoomed: Option<PanePath>,
+}
+
+impl ZoomState {
+    /// Creates a state with nothing zoomed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Zooms the pane or split at the given path.
+    pub fn zoom(&mut self, path: &[usize]) {
+        self.zoomed = Some(path.to_vec());
+    }
+
+    /// Shows the whole layout tree again.
+    pub fn unzoom(&mut self) {
+        self.zoomed = None;
+    }
+
+    /// Unzooms if anything is zoomed, and zooms the given path otherwise.
+    pub fn toggle(&mut self, path: &[usize]) {
+        match self.zoomed {
+            Some(_) => self.unzoom(),
+            None => self.zoom(path),
+        }
+    }
+
+    /// Returns the path of the zoomed pane or split.
+    pub fn zoomed(&self) -> Option<&[usize]> {
+        self.zoomed.as_deref()
+    }
+
+    /// Returns whether anything is zoomed.
+    pub fn is_zoomed(&self) -> bool {
+        self.zoomed.is_some()
+    }
+}
+
+/// Renders a layout tree, or only its zoomed pane as set in a [`ZoomState`].
+///
+/// If nothing is at the zoomed path, the whole tree is rendered.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, widgets::{Paragraph, StatefulWidget}};
+/// use ratcl::{Columns, Rows, Zoom, ZoomState};
+///
+/// struct App {
+///     zoom: ZoomState,
+/// }
+///
+/// impl App {
+///     fn render(&mut self, area: Rect, buffer: &mut Buffer) {
+///         let layout = Columns(
+///             Paragraph::new("Sidebar"),
+///             Rows(
+///                 Paragraph::new("Body"),
+///                 Paragraph::new("Footer"),
+///                 Constraint::Fill(1),
+///             ),
+///             Constraint::Length(20),
+///         );
+///
+///         Zoom(layout).render(area, buffer, &mut self.zoom);
+///     }
+///
+///     fn on_key(&mut self, key: char) {
+///         if key == 'z' {
+///             // Zooms the body.
+///             self.zoom.toggle(&[1, 0]);
+///         }
+///     }
+/// }
+/// ```
+pub struct Zoom<Tree>(pub Tree);
+
+impl <Tree: RenderPane> StatefulWidget for Zoom<Tree> {
+    type State = ZoomState;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        let zoomed = state.zoomed()
+            .unwrap_or_default();
+
+        if !self.0.render_pane(zoomed, area, buffer) {
+            self.0.render_pane(&[], area, buffer);
+        }
+    }
+}
+
+impl <Id, Content: RenderRef> RenderPane for Pane<Id, Content> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        render_leaf(self, path, area, buffer)
+    }
+}
+
+impl <Tree: RenderPane + ?Sized> RenderPane for &Tree {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        (**self).render_pane(path, area, buffer)
+    }
+}
+
+impl <Tree: RenderPane + ?Sized> RenderPane for Box<Tree> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        (**self).render_pane(path, area, buffer)
+    }
+}
+
+macro_rules! impl_leaf_render_panes {
+    ($($leaf:ty),+ $(,)?) => {
+        $(impl RenderPane for $leaf {
+            fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+                render_leaf(self, path, area, buffer)
+            }
+        })+
+    };
+}
+
+impl_leaf_render_panes!(
+    EmptyWidget,
+    Clear,
+    &str,
+    String,
+    Span<'_>,
+    Line<'_>,
+    Text<'_>,
+    Block<'_>,
+    Paragraph<'_>,
+    List<'_>,
+    Table<'_>,
+    Tabs<'_>,
+    Gauge<'_>,
+    LineGauge<'_>,
+    Sparkline<'_>,
+    BarChart<'_>,
+    Chart<'_>,
+);
+
+impl <F: Fn(&mut Context)> RenderPane for Canvas<'_, F> {
+    fn render_pane(&self, path: &[usize], area: Rect, buffer: &mut Buffer) -> bool {
+        render_leaf(self, path, area, buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Constraint;
+
+    use crate::{Columns, Node, Rows, Split};
+
+    use super::*;
+
+    #[test]
+    fn renders_only_zoomed_pane() {
+        let render = |tree: &dyn RenderPane, state: &mut ZoomState| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+
+            Zoom(tree).render(buffer.area, &mut buffer, state);
+
+            buffer
+        };
+        let layout = Columns(
+            "ab",
+            Rows(
+                "cd",
+                Split::columns([("e", Constraint::Length(1)), ("f", Constraint::Fill(1))]).spacing(1),
+                Constraint::Length(1),
+            ),
+            Constraint::Length(3),
+        );
+        let node: Node = Node::Rows(vec![
+            (Constraint::Length(1), Node::leaf("top")),
+            (Constraint::Fill(1), Node::leaf("bottom")),
+        ]);
+        let mut state = ZoomState::new();
+
+        assert_eq!(render(&layout, &mut state), Buffer::with_lines(["ab cd ", "   e f"]));
+
+        state.toggle(&[1, 1]);
+        assert_eq!(render(&layout, &mut state), Buffer::with_lines(["e f   ", "      "]));
+
+        state.zoom(&[1, 1, 1]);
+        assert_eq!(render(&layout, &mut state), Buffer::with_lines(["f     ", "      "]));
+
+        state.zoom(&[1]);
+        assert_eq!(render(&node, &mut state), Buffer::with_lines(["bottom", "      "]));
+
+        state.zoom(&[5]);
+        assert_eq!(render(&node, &mut state), Buffer::with_lines(["top   ", "bottom"]));
+
+        state.toggle(&[0]);
+        assert!(!state.is_zoomed());
+    }
+}